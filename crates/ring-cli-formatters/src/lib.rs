@@ -1,3 +1,5 @@
 mod list_formatter;
+mod snippet_formatter;
 
-pub use list_formatter::ListFormatter;
\ No newline at end of file
+pub use list_formatter::ListFormatter;
+pub use snippet_formatter::SnippetFormatter;
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+use textwrap::core::display_width;
+
+/// Formats a message pointing at a location in a source file, like rustc diagnostics
+#[derive(Debug)]
+pub struct SnippetFormatter {
+    message: String,
+    location: Option<String>,
+    source: Option<(usize, String, usize)>,
+}
+
+impl SnippetFormatter {
+    pub fn new(message: &dyn Display) -> SnippetFormatter {
+        SnippetFormatter {
+            message: message.to_string(),
+            location: None,
+            source: None,
+        }
+    }
+
+    /// Sets location printed below the message (like `Cargo.toml:3:11`)
+    pub fn with_location(mut self, location: &dyn Display) -> SnippetFormatter {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    /// Sets source line to print, with a caret under given column. Line and column start at 1
+    pub fn with_source(mut self, line: usize, source: &str, column: usize) -> SnippetFormatter {
+        self.source = Some((line, source.to_string(), column));
+        self
+    }
+}
+
+impl Display for SnippetFormatter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = self.source.as_ref().map_or(0, |(line, _, _)| line.to_string().len());
+
+        write!(f, "{}", self.message)?;
+
+        if let Some(location) = &self.location {
+            write!(f, "\n{:gutter$}--> {location}", "")?;
+        }
+
+        if let Some((line, source, column)) = &self.source {
+            let before = source.chars().take(column.saturating_sub(1)).collect::<String>();
+
+            writeln!(f, "\n{:gutter$} |", "")?;
+            writeln!(f, "{line} | {source}")?;
+            write!(f, "{:gutter$} | {:width$}^", "", "", width = display_width(&before))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_point_at_column_in_source() {
+        let snippet = SnippetFormatter::new(&"version should be a string")
+            .with_location(&"Cargo.toml:3:11")
+            .with_source(3, "version = 1", 11);
+
+        assert_eq!(
+            format!("{snippet}"),
+            concat!(
+                "version should be a string\n",
+                " --> Cargo.toml:3:11\n",
+                "  |\n",
+                "3 | version = 1\n",
+                "  |           ^"
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_print_only_message_without_source() {
+        let snippet = SnippetFormatter::new(&"invalid manifest");
+
+        assert_eq!(format!("{snippet}"), "invalid manifest");
+    }
+}
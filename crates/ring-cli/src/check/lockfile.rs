@@ -0,0 +1,87 @@
+use std::env;
+use std::path::PathBuf;
+use anyhow::anyhow;
+use clap::{arg, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{info, warn};
+use ring_cli_formatters::ListFormatter;
+use ring_core::{check_lockfile, DependencyGraph, LockfileIssue, RingCore};
+use ring_utils::Normalize;
+use ring_utils::OptionalResult::{Empty, Fail, Found};
+
+pub fn build_command() -> Command {
+    Command::new("lockfile")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let detector = core.scope_detector();
+    let mut list = ListFormatter::new();
+    let mut found_scope = false;
+
+    for scope in detector.detect_from(&path) {
+        let scope = scope?;
+        found_scope = true;
+
+        let packages = match scope.resolved_packages() {
+            Found(packages) => packages,
+            Fail(err) => return Err(err.into()),
+            Empty => {
+                warn!("No lockfile found in scope {}", scope.root().display());
+                continue;
+            }
+        };
+
+        let mut projects = scope.projects().collect::<ring_utils::Result<Vec<_>>>()?;
+
+        let root: ring_utils::Result<Option<_>> = scope.root_project().into();
+
+        if let Some(root) = root? {
+            if !projects.iter().any(|project| project.root() == root.root()) {
+                projects.insert(0, root);
+            }
+        }
+
+        let graph = DependencyGraph::new(packages);
+
+        for issue in check_lockfile(&projects, &graph) {
+            match issue {
+                LockfileIssue::Missing { project, dependency } => list.add_row([
+                    &"missing".red(),
+                    &project.bold(),
+                    &format!("{} {}", dependency.name(), dependency.requirement().bright_black()),
+                ]),
+                LockfileIssue::OutOfRange { project, dependency, locked } => list.add_row([
+                    &"out of range".yellow(),
+                    &project.bold(),
+                    &format!("{} {} (locked {})", dependency.name(), dependency.requirement().bright_black(), locked.iter().join(", ")),
+                ]),
+                LockfileIssue::Extraneous { package } => list.add_row([
+                    &"extraneous".bright_black(),
+                    &"",
+                    &format!("{}@{}", package.name, package.version.bright_black()),
+                ]),
+            }
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+        Err(anyhow!("Found {} lockfile issue(s)", list.len()))
+    } else {
+        if found_scope {
+            info!("Lockfiles are up to date");
+        } else {
+            warn!("No matching scope found");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::path::PathBuf;
+use clap::{arg, ArgMatches, Command, value_parser};
+use tracing::{info, warn};
+use ring_core::RingCore;
+use ring_utils::{Error, ManifestError, Normalize};
+use ring_utils::OptionalResult::Fail;
+use crate::diagnostics::render_error;
+
+pub fn build_command() -> Command {
+    Command::new("manifests")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let mut errors = Vec::new();
+    let mut found_scope = false;
+
+    for scope in core.scope_detector().detect_from(&path) {
+        let scope = match scope {
+            Ok(scope) => scope,
+            Err(err) => {
+                errors.push(anyhow::Error::from(err));
+                continue;
+            }
+        };
+
+        found_scope = true;
+
+        if let Fail(err) = scope.root_project() {
+            errors.push(anyhow::Error::from(err));
+        }
+
+        errors.extend(scope.projects().filter_map(Result::err).map(anyhow::Error::from));
+
+        if let Fail(err) = scope.resolved_packages() {
+            errors.push(anyhow::Error::from(err));
+        }
+    }
+
+    // Root projects are also often scope members
+    let mut reported = BTreeSet::new();
+    errors.retain(|err| reported.insert(format!("{err:#}")));
+
+    for err in &errors {
+        println!("{}\n", render_error(err.as_ref()));
+    }
+
+    if !errors.is_empty() {
+        // Exits like a manifest error
+        Err(Error::from(ManifestError::new(format!("Found {} invalid manifest(s)", errors.len()))).into())
+    } else {
+        if found_scope {
+            info!("Manifests are valid");
+        } else {
+            warn!("No matching scope found");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+mod lockfile;
+mod manifests;
+mod names;
+
+use clap::{ArgMatches, Command};
+use ring_core::RingCore;
+
+pub fn build_command() -> Command {
+    Command::new("check")
+        .subcommand_required(true)
+        .subcommands([
+            lockfile::build_command(),
+            manifests::build_command(),
+            names::build_command(),
+        ])
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    match args.subcommand() {
+        Some(("lockfile", args)) => lockfile::handle_command(core, args),
+        Some(("manifests", args)) => manifests::handle_command(core, args),
+        Some(("names", args)) => names::handle_command(core, args),
+        _ => unreachable!()
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::path::PathBuf;
+use anyhow::anyhow;
+use clap::{arg, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{info, warn};
+use ring_cli_formatters::ListFormatter;
+use ring_core::{check_names, NameIssue, RingCore};
+use ring_utils::{Error, ManifestError, Normalize};
+use ring_utils::OptionalResult::{Empty, Fail, Found};
+use crate::diagnostics::render_error;
+
+pub fn build_command() -> Command {
+    Command::new("names")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let rule = core.config().names.directory.value;
+    let (tree, errors) = core.scope_tree_from(&path);
+
+    if !errors.is_empty() {
+        for err in &errors {
+            eprintln!("\n{}", render_error(err));
+        }
+
+        eprintln!();
+
+        // Exits like a manifest error
+        return Err(Error::from(ManifestError::new(format!("{} scope(s) could not be loaded", errors.len()))).into());
+    }
+
+    let mut list = ListFormatter::new();
+    let mut reported = BTreeSet::new();
+
+    for workspace in tree.iter() {
+        let mut projects = Vec::new();
+
+        for (_, scope) in workspace.scopes() {
+            match scope.root_project() {
+                Found(root) => projects.push(root),
+                Fail(err) => return Err(err.into()),
+                Empty => {}
+            }
+
+            for project in scope.projects() {
+                projects.push(project?);
+            }
+        }
+
+        for issue in check_names(&projects, rule) {
+            match issue {
+                NameIssue::Duplicate { name, roots } => list.add_row([
+                    &"duplicate".red(),
+                    &name.bold(),
+                    &roots.iter().map(|root| root.display()).join(", "),
+                ]),
+                // Workspace root directory is named after the repository, not after its root project
+                NameIssue::Directory { root, .. } if root == workspace.root() => continue,
+                NameIssue::Directory { name, root } => {
+                    // Nested workspace root projects are often also members of the parent workspace
+                    if !reported.insert(root.to_path_buf()) {
+                        continue;
+                    }
+
+                    list.add_row([
+                        &"directory".yellow(),
+                        &name.bold(),
+                        &format!("{} {}", root.display(), format!("(expected {rule} name)").bright_black()),
+                    ]);
+                }
+            }
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+        Err(anyhow!("Found {} name issue(s)", list.len()))
+    } else {
+        if !tree.is_empty() {
+            info!("Project names are valid");
+        } else {
+            warn!("No matching scope found");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+mod show;
+
+use clap::{ArgMatches, Command};
+use ring_core::RingCore;
+
+pub fn build_command() -> Command {
+    Command::new("config")
+        .subcommand_required(true)
+        .subcommands([
+            show::build_command(),
+        ])
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    match args.subcommand() {
+        Some(("show", _)) => show::handle_command(core),
+        _ => unreachable!()
+    }
+}
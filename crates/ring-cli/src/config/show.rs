@@ -0,0 +1,65 @@
+use clap::Command;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::info;
+use ring_cli_formatters::ListFormatter;
+use ring_core::{ConfigSource, RingCore};
+
+pub fn build_command() -> Command {
+    Command::new("show")
+}
+
+pub fn handle_command(core: &RingCore) -> anyhow::Result<()> {
+    let config = core.config();
+    let mut list = ListFormatter::new();
+
+    if config.sources.is_empty() {
+        info!("No config file found, using defaults");
+    }
+
+    let modules = match &config.modules.value {
+        Some(modules) => modules.iter().join(", "),
+        None => "all".to_string(),
+    };
+
+    list.add_row([&"modules".bold(), &modules, &format_source(&config.modules.source)]);
+
+    for pattern in &config.ignore {
+        list.add_row([&"ignore".bold(), &pattern.value, &format_source(&pattern.source)]);
+    }
+
+    for pattern in &config.projects {
+        list.add_row([&"projects".bold(), &pattern.value.display(), &format_source(&pattern.source)]);
+    }
+
+    for (name, task) in &config.tasks {
+        list.add_row([&format!("tasks.{name}").bold(), &task.value.command, &format_source(&task.source)]);
+    }
+
+    for (name, plugin) in &config.plugins {
+        let command = [plugin.value.command.as_str()].into_iter()
+            .chain(plugin.value.args.iter().map(String::as_str))
+            .join(" ");
+
+        list.add_row([&format!("plugins.{name}").bold(), &command, &format_source(&plugin.source)]);
+    }
+
+    for (name, project_type) in &config.types {
+        list.add_row([&format!("types.{name}").bold(), &project_type.value.marker, &format_source(&project_type.source)]);
+    }
+
+    list.add_row([&"names.directory".bold(), &config.names.directory.value, &format_source(&config.names.directory.source)]);
+    list.add_row([&"output.verbose".bold(), &config.output.verbose.value, &format_source(&config.output.verbose.source)]);
+
+    println!("{list}");
+
+    Ok(())
+}
+
+fn format_source(source: &ConfigSource) -> String {
+    match source {
+        ConfigSource::Default => "default".bright_black().to_string(),
+        ConfigSource::User(path) => format!("{} {}", path.display(), "(user)".bright_black()),
+        ConfigSource::Project(path) => path.display().to_string(),
+    }
+}
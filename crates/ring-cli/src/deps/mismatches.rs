@@ -0,0 +1,105 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::env;
+use std::path::PathBuf;
+use clap::{arg, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{info, warn};
+use ring_cli_formatters::ListFormatter;
+use ring_core::{DependencyGraph, RingCore};
+use ring_traits::Dependency;
+use ring_utils::Normalize;
+use ring_utils::OptionalResult::{Empty, Fail, Found};
+
+pub fn build_command() -> Command {
+    Command::new("mismatches")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let detector = core.scope_detector();
+    let mut list = ListFormatter::new();
+    let mut found_scope = false;
+
+    for scope in detector.detect_from(&path) {
+        let scope = scope?;
+        let projects = scope.projects().collect::<ring_utils::Result<Vec<_>>>()?;
+        found_scope = true;
+
+        let graph = match scope.resolved_packages() {
+            Found(packages) => Some(DependencyGraph::new(packages)),
+            Fail(err) => return Err(err.into()),
+            Empty => None,
+        };
+
+        // Local projects are not third-party dependencies
+        let local_names: HashSet<&str> = projects.iter()
+            .map(|project| project.name())
+            .collect();
+
+        // dependency => used version => projects
+        let mut usages: BTreeMap<String, BTreeMap<String, BTreeSet<String>>> = BTreeMap::new();
+
+        for project in &projects {
+            for dependency in project.dependencies() {
+                if local_names.contains(dependency.name()) {
+                    continue;
+                }
+
+                usages.entry(dependency.name().to_string())
+                    .or_default()
+                    .entry(used_version(graph.as_ref(), &dependency))
+                    .or_default()
+                    .insert(project.name().to_string());
+            }
+        }
+
+        for (dependency, versions) in usages {
+            if versions.len() < 2 {
+                continue;
+            }
+
+            info!("Found {} versions of {} in scope {}", versions.len(), dependency, scope.root().display());
+
+            for (idx, (version, projects)) in versions.iter().enumerate() {
+                let name = if idx == 0 { dependency.bold().to_string() } else { String::new() };
+
+                list.add_row([
+                    &name,
+                    &version,
+                    &projects.iter().join(", "),
+                ]);
+            }
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+    } else if found_scope {
+        info!("No dependency mismatch found");
+    } else {
+        warn!("No matching scope found");
+    }
+
+    Ok(())
+}
+
+/// Returns versions of given dependency resolved by the lockfile, or its parsed requirement
+/// if it has no lockfile entry, so equivalent requirements are not reported as mismatches
+fn used_version(graph: Option<&DependencyGraph>, dependency: &Dependency) -> String {
+    let resolved = graph.map(|graph| graph.resolve(dependency)).unwrap_or_default();
+
+    if !resolved.is_empty() {
+        resolved.iter().map(|package| package.version.as_str()).sorted().dedup().join(", ")
+    } else if !dependency.version_reqs().is_empty() {
+        dependency.version_reqs().iter().join(" || ")
+    } else {
+        dependency.requirement().to_string()
+    }
+}
@@ -0,0 +1,23 @@
+mod mismatches;
+mod why;
+
+use clap::{ArgMatches, Command};
+use ring_core::RingCore;
+
+pub fn build_command() -> Command {
+    Command::new("deps")
+        .aliases(["dependencies"])
+        .subcommand_required(true)
+        .subcommands([
+            mismatches::build_command(),
+            why::build_command(),
+        ])
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    match args.subcommand() {
+        Some(("mismatches", args)) => mismatches::handle_command(core, args),
+        Some(("why", args)) => why::handle_command(core, args),
+        _ => unreachable!()
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::env;
+use std::path::PathBuf;
+use std::rc::Rc;
+use clap::{arg, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::warn;
+use ring_core::{DependencyGraph, RingCore};
+use ring_traits::{DependencyKind, Project, ResolvedPackage};
+use ring_utils::Normalize;
+
+pub fn build_command() -> Command {
+    Command::new("why")
+        .arg(arg!(<name> "Name of the package to explain"))
+        .arg(arg!([path] "Path of the project to search in")
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+    let name = args.get_one::<String>("name").unwrap();
+
+    let detector = core.project_detector();
+    let mut found_project = false;
+    let mut chains = Vec::new();
+
+    for project in detector.detect_from(&path) {
+        let project = project?;
+        found_project = true;
+
+        let scope = core.find_scope(&project)?;
+        let mut locals = match &scope {
+            Some(scope) => scope.projects().collect::<ring_utils::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
+        if let Some(scope) = &scope {
+            let root: ring_utils::Result<Option<_>> = scope.root_project().into();
+            locals.extend(root?);
+        }
+
+        if !locals.iter().any(|local| local.root() == project.root()) {
+            locals.push(project.clone());
+        }
+
+        let graph = match &scope {
+            Some(scope) => DependencyGraph::new(scope.resolved_packages().result_or_default()?),
+            None => DependencyGraph::default(),
+        };
+
+        explain(&project, &locals, &graph, name, &mut chains);
+    }
+
+    if !found_project {
+        warn!("No matching project found");
+    } else if chains.is_empty() {
+        warn!("{name} is not a dependency of this project");
+    } else {
+        for chain in chains.iter().unique() {
+            println!("{chain}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Walks through local projects, from given project, searching for `name`
+fn explain(project: &Rc<dyn Project>, locals: &[Rc<dyn Project>], graph: &DependencyGraph, name: &str, chains: &mut Vec<String>) {
+    let locals: HashMap<&str, &Rc<dyn Project>> = locals.iter()
+        .map(|local| (local.name(), local))
+        .collect();
+
+    let mut visited = HashSet::from([project.name()]);
+    let mut queue = VecDeque::from([vec![project.name()]]);
+
+    while let Some(path) = queue.pop_front() {
+        let current = locals.get(path.last().unwrap()).map_or(project, |local| local);
+
+        // Dev dependencies are only installed for the project itself
+        let dependencies = current.dependencies().into_iter()
+            .filter(|dependency| path.len() == 1 || dependency.kind() != DependencyKind::Dev);
+
+        for dependency in dependencies {
+            if let Some(local) = locals.get(dependency.name()) {
+                let local_path = [path.as_slice(), &[local.name()]].concat();
+
+                if local.name() == name {
+                    chains.push(format_chain(&local_path, &[]));
+                }
+
+                if visited.insert(local.name()) {
+                    queue.push_back(local_path);
+                }
+            } else if dependency.name() == name {
+                let resolved = graph.resolve(&dependency);
+
+                let node = match resolved.as_slice() {
+                    [package] => format_package(package),
+                    _ => format!("{}@{}", name, dependency.requirement().bright_black()),
+                };
+
+                chains.push(format_chain(&path, &[node]));
+            } else {
+                for chain in graph.chains(&dependency, name) {
+                    let nodes = chain.iter()
+                        .map(|package| format_package(package))
+                        .collect::<Vec<_>>();
+
+                    chains.push(format_chain(&path, &nodes));
+                }
+            }
+        }
+    }
+}
+
+fn format_package(package: &ResolvedPackage) -> String {
+    format!("{}@{}", package.name, package.version.bright_black())
+}
+
+fn format_chain(locals: &[&str], packages: &[String]) -> String {
+    locals.iter()
+        .map(|local| local.bold().to_string())
+        .chain(packages.iter().cloned())
+        .join(" → ")
+}
@@ -0,0 +1,98 @@
+use std::error::Error as StdError;
+use owo_colors::OwoColorize;
+use ring_cli_formatters::SnippetFormatter;
+use ring_utils::{Error, ErrorKind, ManifestError};
+
+/// Searches the outermost ring error in given error chain
+fn find_error<'a>(error: &'a (dyn StdError + 'static)) -> Option<&'a Error> {
+    let mut current = Some(error);
+
+    while let Some(err) = current {
+        if let Some(err) = err.downcast_ref::<Error>() {
+            return Some(err);
+        }
+
+        current = err.source();
+    }
+
+    None
+}
+
+/// Searches a manifest error in given error chain
+pub fn find_manifest_error<'a>(error: &'a (dyn StdError + 'static)) -> Option<&'a ManifestError> {
+    find_error(error).and_then(Error::manifest_error)
+}
+
+/// Formats every message of given error chain, like anyhow's alternate format
+pub fn format_chain(error: &(dyn StdError + 'static)) -> String {
+    let mut messages = vec![error.to_string()];
+    let mut current = error.source();
+
+    while let Some(err) = current {
+        messages.push(err.to_string());
+        current = err.source();
+    }
+
+    messages.join(": ")
+}
+
+/// Renders given error, with a snippet of the manifest if it is an invalid manifest
+pub fn render_error(error: &(dyn StdError + 'static)) -> String {
+    match find_manifest_error(error) {
+        Some(error) => manifest_snippet(error).to_string(),
+        None => format!("{}: {}", "error".red().bold(), format_chain(error)),
+    }
+}
+
+/// Returns exit code matching kind of given error, following `sysexits.h` conventions.
+/// Errors not raised by ring libraries (like failed checks) exit with 1.
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    match find_error(error.as_ref()).map(Error::kind) {
+        Some(ErrorKind::Pattern) => 64,
+        Some(ErrorKind::Manifest) => 65,
+        Some(ErrorKind::NotFound) => 66,
+        Some(ErrorKind::Command) => 69,
+        Some(ErrorKind::Io) => 74,
+        Some(ErrorKind::PermissionDenied) => 77,
+        Some(ErrorKind::Config) => 78,
+        None => 1,
+    }
+}
+
+/// Renders given manifest error with the offending line of the manifest
+pub fn manifest_snippet(error: &ManifestError) -> SnippetFormatter {
+    let mut snippet = SnippetFormatter::new(&format!("{}: {}", "error".red().bold(), error.explanation().bold()));
+
+    if let Some(path) = error.path() {
+        let location = match (error.line(), error.column()) {
+            (Some(line), Some(column)) => format!("{}:{line}:{column}", path.display()),
+            _ => path.display().to_string(),
+        };
+
+        snippet = snippet.with_location(&location.bright_black());
+    }
+
+    if let (Some(line), Some(column), Some(source)) = (error.line(), error.column(), error.source_line()) {
+        snippet = snippet.with_source(line, source, column);
+    }
+
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use anyhow::Context;
+    use super::*;
+
+    #[test]
+    fn it_should_map_error_kinds_to_exit_codes() {
+        let denied = Error::io("/test", io::Error::from(io::ErrorKind::PermissionDenied));
+        let manifest: anyhow::Result<()> = Err(Error::from(ManifestError::new("missing field `name`")))
+            .context("Unable to list projects");
+
+        assert_eq!(exit_code(&anyhow::Error::from(denied)), 77);
+        assert_eq!(exit_code(&manifest.unwrap_err()), 65);
+        assert_eq!(exit_code(&anyhow::anyhow!("Found 2 issues")), 1);
+    }
+}
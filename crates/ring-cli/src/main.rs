@@ -1,34 +1,76 @@
+use std::env;
 use std::io;
+use std::process::ExitCode;
 use anyhow::Result;
 use clap::{arg, ArgAction, Command};
 use tracing::Level;
 use tracing_subscriber::FmtSubscriber;
-use ring_core::RingCore;
+use ring_core::{Config, RingCore};
+use ring_utils::Normalize;
 
+mod check;
+mod config;
+mod deps;
+mod diagnostics;
 mod list;
 mod modules;
 mod projects;
+mod rust;
+mod scopes;
+mod r#where;
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            match diagnostics::find_manifest_error(err.as_ref()) {
+                Some(error) => eprintln!("{}", diagnostics::manifest_snippet(error)),
+                None => eprintln!("Error: {err:?}"),
+            }
+
+            ExitCode::from(diagnostics::exit_code(&err))
+        }
+    }
+}
+
+fn run() -> Result<()> {
     // Setup commands
     let args = Command::new("ring")
         .version(env!("RING_CLI_VERSION"))
         .propagate_version(true)
         .subcommand_required(true)
         .subcommands([
+            check::build_command(),
+            config::build_command(),
+            deps::build_command(),
             list::build_command(),
             modules::build_command(),
-            projects::build_command()
+            projects::build_command(),
+            rust::build_command(),
+            scopes::build_command(),
+            r#where::build_command(),
         ])
+        .arg(arg!(--modules <modules> "Comma separated list of modules to enable")
+            .global(true)
+            .required(false)
+            .value_delimiter(','))
         .arg(arg!(-v --verbose)
             .global(true)
             .required(false)
             .action(ArgAction::Count))
         .get_matches();
 
+    // Load configuration
+    let config = Config::load(&env::current_dir()?.normalize())?;
+
     // Setup tracing
+    let verbose = match args.get_count("verbose") {
+        0 => config.output.verbose.value,
+        count => count,
+    };
+
     let subscriber = FmtSubscriber::builder()
-        .with_max_level(match args.get_count("verbose") {
+        .with_max_level(match verbose {
             0 => Level::WARN,
             1 => Level::INFO,
             2 => Level::DEBUG,
@@ -42,12 +84,22 @@ fn main() -> Result<()> {
     tracing::subscriber::set_global_default(subscriber)?;
 
     // Handle subcommands
-    let core = RingCore::new();
-    
+    let mut core = RingCore::with_config(config)?;
+
+    if let Some(modules) = args.get_many::<String>("modules") {
+        core.registry_mut().enable_only(&modules.collect::<Vec<_>>())?;
+    }
+
     match args.subcommand() {
+        Some(("check", args)) => check::handle_command(&core, args),
+        Some(("config", args)) => config::handle_command(&core, args),
+        Some(("deps", args)) => deps::handle_command(&core, args),
         Some(("list", args)) => list::handle_command(&core, args),
         Some(("modules", args)) => modules::handle_command(&core, args),
         Some(("projects", args)) => projects::handle_command(&core, args),
+        Some(("rust", args)) => rust::handle_command(&core, args),
+        Some(("scopes", args)) => scopes::handle_command(&core, args),
+        Some(("where", args)) => r#where::handle_command(&core, args),
         _ => unreachable!()
     }
 }
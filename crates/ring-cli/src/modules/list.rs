@@ -1,4 +1,6 @@
 use clap::Command;
+use owo_colors::OwoColorize;
+use ring_cli_formatters::ListFormatter;
 use ring_core::RingCore;
 
 pub fn build_command() -> Command {
@@ -7,9 +9,17 @@ pub fn build_command() -> Command {
 }
 
 pub fn handle_command(core: &RingCore) -> anyhow::Result<()> {
-    for module in core.modules() {
-        println!("{}", module.name());
+    let mut list = ListFormatter::new();
+
+    for (module, enabled) in core.registry().all_modules() {
+        if enabled {
+            list.add_row([&module.name(), &"enabled".green()]);
+        } else {
+            list.add_row([&module.name(), &"disabled".bright_black()]);
+        }
     }
-    
+
+    println!("{list}");
+
     Ok(())
-}
\ No newline at end of file
+}
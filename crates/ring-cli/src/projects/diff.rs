@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+use clap::{arg, ArgMatches, Command, value_parser};
+use owo_colors::OwoColorize;
+use tracing::info;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_utils::{Normalize, NormalizedPath, NormalizedPathBuf};
+
+pub fn build_command() -> Command {
+    Command::new("diff")
+        .arg(arg!(<from> "Base revision"))
+        .arg(arg!(<to> "Compared revision"))
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let from = projects_at(core, &path, args.get_one::<String>("from").unwrap())?;
+    let to = projects_at(core, &path, args.get_one::<String>("to").unwrap())?;
+
+    let mut list = ListFormatter::new();
+
+    for (key @ (name, root), version) in &to {
+        match from.get(key) {
+            None => list.add_row([&"+".green(), name, &format_version(version), &root.display().bright_black()]),
+            Some(old) if old != version => list.add_row([
+                &"~".yellow(),
+                name,
+                &format!("{} -> {}", format_version(old), format_version(version)),
+                &root.display().bright_black(),
+            ]),
+            Some(_) => {}
+        }
+    }
+
+    for (key @ (name, root), version) in &from {
+        if !to.contains_key(key) {
+            list.add_row([&"-".red(), name, &format_version(version), &root.display().bright_black()]);
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+    } else {
+        info!("No project changed");
+    }
+
+    Ok(())
+}
+
+/// Lists versions of projects at given revision, by name and root.
+/// Many projects may share a name (in different ecosystems for example).
+fn projects_at(core: &RingCore, path: &NormalizedPath, revision: &str) -> anyhow::Result<BTreeMap<(String, NormalizedPathBuf), Option<String>>> {
+    let core = core.at_revision(path, revision)?;
+
+    Ok(core.projects_from(path)?.into_iter()
+        .map(|project| (
+            (project.name().to_string(), project.root().to_path_buf()),
+            project.version().map(|version| version.to_string()),
+        ))
+        .collect())
+}
+
+fn format_version(version: &Option<String>) -> String {
+    version.clone().unwrap_or_else(|| "none".bright_black().to_string())
+}
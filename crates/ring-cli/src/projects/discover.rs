@@ -0,0 +1,53 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use clap::{arg, ArgAction, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::warn;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_utils::Normalize;
+
+pub fn build_command() -> Command {
+    Command::new("discover")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+        .arg(arg!(--orphans "Only show projects not declared by any scope")
+            .action(ArgAction::SetTrue))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir.clone());
+    let orphans_only = args.get_flag("orphans");
+
+    let mut list = ListFormatter::new();
+
+    for discovered in core.discover_projects(&path)? {
+        if orphans_only && !discovered.orphan {
+            continue;
+        }
+
+        let project = discovered.project;
+        let root = project.root().as_path().strip_prefix(&current_dir)
+            .unwrap_or(project.root().as_path());
+        let root = if root.as_os_str().is_empty() { Path::new(".") } else { root };
+
+        list.add_row([
+            &project.name(),
+            &project.tags().iter().join("/"),
+            &root.display().bright_black(),
+            &if discovered.orphan { "orphan".yellow().to_string() } else { String::new() },
+        ]);
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+    } else {
+        warn!("No project found");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::env;
+use std::path::{self, Path};
+use std::rc::Rc;
+use anyhow::bail;
+use clap::{arg, ArgMatches, Command};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_traits::Project;
+use ring_utils::{Normalize, NormalizedPath, OptionalResult};
+use crate::projects::resolve_project;
+
+pub fn build_command() -> Command {
+    Command::new("info")
+        .visible_alias("show")
+        .arg(arg!([project] "Name or path of the project (defaults to the current project)"))
+        .arg(arg!(--rev <rev> "Show project as it was at given git revision"))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+
+    let revision_core = args.get_one::<String>("rev")
+        .map(|rev| core.at_revision(&current_dir, rev))
+        .transpose()?;
+    let core = revision_core.as_ref().unwrap_or(core);
+
+    let projects = match args.get_one::<String>("project") {
+        Some(path) if is_path(path) => detect_from(core, &Path::new(path).resolve(&current_dir))?,
+        Some(name) => vec![resolve_project(core, &current_dir, name)?],
+        None => detect_from(core, &current_dir)?,
+    };
+
+    if projects.is_empty() {
+        bail!("No matching project found");
+    }
+
+    let blocks = projects.iter()
+        .map(|project| describe(core, project))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    println!("{}", blocks.join("\n\n"));
+
+    Ok(())
+}
+
+/// Returns `true` if given argument is a path rather than a project name, meaning it starts with
+/// `.` or contains a separator. Scoped npm names, like `@acme/ui`, are still names.
+fn is_path(arg: &str) -> bool {
+    arg.starts_with('.') || (!arg.starts_with('@') && arg.chars().any(path::is_separator))
+}
+
+fn detect_from(core: &RingCore, path: &NormalizedPath) -> anyhow::Result<Vec<Rc<dyn Project>>> {
+    Ok(core.project_detector().detect_from(&path).collect::<ring_utils::Result<_>>()?)
+}
+
+/// Returns name of the module which detected given project
+fn find_module<'a>(core: &'a RingCore, project: &Rc<dyn Project>) -> Option<&'a str> {
+    core.modules().into_iter()
+        .find(|module| module.project_detectors().iter()
+            .any(|detector| match detector.detect_at_as(project.root()) {
+                OptionalResult::Found(detected) => detected.name() == project.name(),
+                _ => false,
+            }))
+        .map(|module| module.name())
+}
+
+fn describe(core: &RingCore, project: &Rc<dyn Project>) -> anyhow::Result<String> {
+    let mut list = ListFormatter::new();
+
+    list.add_row([&"name".bold(), &project.name()]);
+
+    if let Some(version) = project.version() {
+        list.add_row([&"version".bold(), version]);
+    }
+
+    list.add_row([&"root".bold(), &project.root().display()]);
+
+    if let Some(manifest) = project.manifest_path() {
+        list.add_row([&"manifest".bold(), &manifest.display()]);
+    }
+
+    if let Some(module) = find_module(core, project) {
+        list.add_row([&"module".bold(), &module]);
+    }
+
+    list.add_row([&"tags".bold(), &project.tags().iter().join("/")]);
+
+    // Scope, local dependencies and dependents
+    let scope = core.find_scope(project)?;
+    let mut locals = Vec::new();
+
+    if let Some(scope) = &scope {
+        list.add_row([&"scope".bold(), &format!("{} {}", scope.root().display(), scope.tags().iter().join("/"))]);
+        locals = scope.projects().collect::<ring_utils::Result<Vec<_>>>()?;
+    }
+
+    let dependencies = project.dependencies().into_iter()
+        .filter(|dependency| locals.iter().any(|local| local.name() == dependency.name()))
+        .map(|dependency| format!("{} {}", dependency.name(), format!("({})", dependency.kind()).bright_black()))
+        .collect::<Vec<_>>();
+
+    add_rows(&mut list, "dependencies", &dependencies);
+
+    let dependents = locals.iter()
+        .filter_map(|local| local.dependencies().into_iter()
+            .find(|dependency| dependency.name() == project.name())
+            .map(|dependency| format!("{} {}", local.name(), format!("({})", dependency.kind()).bright_black())))
+        .collect::<Vec<_>>();
+
+    add_rows(&mut list, "dependents", &dependents);
+
+    // Ecosystem specific details
+    for (label, values) in &project.details().into_iter().chunk_by(|(label, _)| label.clone()) {
+        add_rows(&mut list, &label, &values.map(|(_, value)| value).collect::<Vec<_>>());
+    }
+
+    Ok(list.to_string())
+}
+
+/// Adds a row for each value, label is only printed on the first one
+fn add_rows(list: &mut ListFormatter<2>, label: &str, values: &[String]) {
+    for (idx, value) in values.iter().enumerate() {
+        let label = if idx == 0 { label.bold().to_string() } else { String::new() };
+        list.add_row([&label, value]);
+    }
+}
@@ -1,17 +1,27 @@
 use std::env;
 use std::path::PathBuf;
-use clap::{arg, ArgMatches, Command, value_parser};
+use std::rc::Rc;
+use anyhow::bail;
+use clap::{arg, ArgAction, ArgMatches, Command, value_parser};
 use itertools::Itertools;
+use serde_json::{json, Value};
 use tracing::warn;
 use ring_cli_formatters::ListFormatter;
 use ring_core::RingCore;
-use ring_utils::Normalize;
+use ring_traits::Project;
+use ring_utils::{Error, Normalize};
+use crate::diagnostics::{format_chain, render_error};
 
 pub fn build_command() -> Command {
     Command::new("list")
         .visible_alias("ls")
         .arg(arg!([path])
             .value_parser(value_parser!(PathBuf)))
+        .arg(arg!(--rev <rev> "List projects as they were at given git revision"))
+        .arg(arg!(--strict "Fail on any warning")
+            .action(ArgAction::SetTrue))
+        .arg(arg!(--json "Print projects and errors as json")
+            .action(ArgAction::SetTrue))
 }
 
 pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
@@ -20,27 +30,87 @@ pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()>
         .map(|path| path.resolve(&current_dir))
         .unwrap_or(current_dir);
 
-    let detector = core.scope_detector();
-    let mut list = ListFormatter::new();
+    let revision_core = args.get_one::<String>("rev")
+        .map(|rev| core.at_revision(&path, rev))
+        .transpose()?;
+    let core = revision_core.as_ref().unwrap_or(core);
 
-    for scope in detector.detect_from(&path) {
-        let scope = scope?;
-        
-        for project in scope.projects() {
-            let project = project?;
+    let (projects, errors) = core.collect_projects_from(&path).into_parts();
+    let strict = args.get_flag("strict");
 
-            list.add_row([
-                &project.name(),
-                &project.tags().iter().join("/")
-            ]);
-        }
+    print_list(&projects, &errors, args.get_flag("json"), strict)?;
+
+    if strict && !errors.is_empty() {
+        bail!("{} project(s) could not be loaded", errors.len());
+    }
+
+    Ok(())
+}
+
+/// Prints projects and errors, the same way in both modes. In strict mode, warnings become errors.
+fn print_list(projects: &[Rc<dyn Project>], errors: &[Error], json: bool, strict: bool) -> anyhow::Result<()> {
+    if json {
+        let output = json!({
+            "projects": projects.iter().map(|project| project_json(project.as_ref())).collect::<Vec<_>>(),
+            "errors": errors.iter().map(error_json).collect::<Vec<_>>(),
+        });
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let mut list = ListFormatter::new();
+
+    for project in projects {
+        list.add_row([
+            &project.name(),
+            &project.tags().iter().join("/")
+        ]);
     }
 
     if !list.is_empty() {
         println!("{list}");
-    } else {
+    } else if errors.is_empty() {
+        if strict {
+            bail!("No matching scope found");
+        }
+
         warn!("No matching scope found");
     }
 
+    if !errors.is_empty() {
+        for err in errors {
+            eprintln!("\n{}", render_error(err));
+        }
+
+        eprintln!();
+
+        if !strict {
+            warn!("{} project(s) could not be loaded, use --strict to fail on them", errors.len());
+        }
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
+
+fn project_json(project: &dyn Project) -> Value {
+    json!({
+        "name": project.name(),
+        "version": project.version().map(|version| version.to_string()),
+        "root": project.root().display().to_string(),
+        "tags": project.tags().iter().map(|tag| tag.label().to_string()).collect::<Vec<_>>(),
+    })
+}
+
+fn error_json(error: &Error) -> Value {
+    let manifest = error.manifest_error();
+
+    json!({
+        "kind": error.kind().to_string(),
+        "message": format_chain(error),
+        "path": manifest.and_then(|err| err.path()).map(|path| path.display().to_string()),
+        "line": manifest.and_then(|err| err.line()),
+        "column": manifest.and_then(|err| err.column()),
+        "key": manifest.and_then(|err| err.key()),
+    })
+}
@@ -1,8 +1,17 @@
 mod current;
+mod diff;
+mod discover;
+mod info;
 mod list;
 
+use std::rc::Rc;
+use anyhow::bail;
 use clap::{ArgMatches, Command};
-use ring_core::RingCore;
+use itertools::Itertools;
+use tracing::info;
+use ring_core::{NameMatch, Resolution, RingCore};
+use ring_traits::Project;
+use ring_utils::NormalizedPath;
 
 pub fn build_command() -> Command {
     Command::new("projects")
@@ -11,6 +20,9 @@ pub fn build_command() -> Command {
         .subcommand_required(true)
         .subcommands([
             current::build_command(),
+            diff::build_command(),
+            discover::build_command(),
+            info::build_command(),
             list::build_command(),
         ])
 }
@@ -18,7 +30,31 @@ pub fn build_command() -> Command {
 pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
     match args.subcommand() {
         Some(("current", _)) => current::handle_command(core),
+        Some(("diff", args)) => diff::handle_command(core, args),
+        Some(("discover", args)) => discover::handle_command(core, args),
+        Some(("info", args)) => info::handle_command(core, args),
         Some(("list", args)) => list::handle_command(core, args),
         _ => unreachable!()
     }
-}
\ No newline at end of file
+}
+
+/// Resolves a project by name among projects listed from given path
+pub fn resolve_project(core: &RingCore, path: &NormalizedPath, name: &str) -> anyhow::Result<Rc<dyn Project>> {
+    match core.resolve_project(path, name)? {
+        Resolution::Found { project, matched } => {
+            if matched != NameMatch::Exact {
+                info!("Resolved {name} to {} ({matched} match)", project.name());
+            }
+
+            Ok(project)
+        }
+        Resolution::Ambiguous { candidates, matched } => {
+            let candidates = candidates.iter()
+                .map(|project| format!("  {} ({})", project.name(), project.root().display()))
+                .join("\n");
+
+            bail!("Project name {name} is ambiguous, it has many {matched} matches:\n{candidates}")
+        }
+        Resolution::NotFound => bail!("No project named {name} found"),
+    }
+}
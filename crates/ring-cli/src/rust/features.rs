@@ -0,0 +1,153 @@
+use std::env;
+use std::path::PathBuf;
+use std::rc::Rc;
+use anyhow::anyhow;
+use clap::{arg, ArgMatches, Command, value_parser};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_rust::{FeatureValue, RustProject};
+use ring_traits::Project;
+use ring_utils::{Normalize, NormalizedPath, OptionalResult};
+
+pub fn build_command() -> Command {
+    Command::new("features")
+        .arg(arg!(<crate> "Name of the crate to inspect"))
+        .arg(arg!([path] "Path of the workspace to search in")
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+    let name = args.get_one::<String>("crate").unwrap();
+
+    let projects = workspace_crates(core, &path)?;
+    let crates = projects.iter()
+        .filter_map(|project| project.as_ref().as_any().downcast_ref::<RustProject>())
+        .collect::<Vec<_>>();
+    let krate = crates.iter()
+        .find(|krate| krate.name() == name)
+        .ok_or_else(|| anyhow!("Crate {name} not found"))?;
+
+    // Feature graph
+    let features = krate.features();
+    let mut list = ListFormatter::new();
+
+    list.add_row([&"default".bold(), &format_values(features.defaults())]);
+
+    for (feature, values) in features.features() {
+        if feature != "default" {
+            list.add_row([&feature.bold(), &format_values(values)]);
+        }
+    }
+
+    if features.features().is_empty() {
+        println!("{} has no features", krate.name());
+    } else {
+        println!("{list}");
+    }
+
+    // Siblings enabling features
+    let mut siblings = ListFormatter::new();
+
+    for sibling in &crates {
+        let groups = [
+            &sibling.manifest().dependencies,
+            &sibling.manifest().dev_dependencies,
+            &sibling.manifest().build_dependencies,
+        ];
+
+        for (key, dependency) in groups.into_iter().flatten() {
+            if dependency.package_name(key) != name {
+                continue;
+            }
+
+            let requested = dependency.default_features().then_some("default").into_iter()
+                .chain(dependency.features().iter().map(String::as_str))
+                .collect::<Vec<_>>();
+
+            // Features implied by requested ones
+            let implied = features.resolve(requested.iter().copied()).into_iter()
+                .filter_map(|value| match value {
+                    FeatureValue::Feature(feature) if !requested.contains(&feature.as_str()) => Some(feature),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            let mut enabled = requested.iter().join(", ");
+
+            if !implied.is_empty() {
+                enabled = format!("{enabled} {}", format!("(+ {})", implied.iter().join(", ")).bright_black());
+            }
+
+            siblings.add_row([&sibling.name().bold(), &"dependency", &enabled]);
+
+            // Sibling features enabling features of this crate
+            for (feature, values) in sibling.features().features() {
+                let enabled = values.iter()
+                    .filter_map(|value| match value {
+                        FeatureValue::DependencyFeature { dependency, feature, .. } if dependency == key => Some(feature),
+                        _ => None,
+                    })
+                    .join(", ");
+
+                if !enabled.is_empty() {
+                    siblings.add_row([&sibling.name().bold(), &format!("feature {feature}"), &enabled]);
+                }
+            }
+        }
+    }
+
+    if !siblings.is_empty() {
+        println!();
+        println!("{}", "Enabled by".bold());
+        println!("{siblings}");
+    }
+
+    Ok(())
+}
+
+/// Loads every crate of the workspace containing given path, or the crate at given path,
+/// using the enabled rust module
+fn workspace_crates(core: &RingCore, path: &NormalizedPath) -> anyhow::Result<Vec<Rc<dyn Project>>> {
+    let module = core.modules().into_iter()
+        .find(|module| module.name() == "rust")
+        .ok_or_else(|| anyhow!("Rust module is not enabled"))?;
+
+    let mut crates = Vec::new();
+
+    for detector in module.scope_detectors() {
+        match detector.detect_from_as(path) {
+            OptionalResult::Found(scope) => {
+                crates.extend(scope.projects().collect::<ring_utils::Result<Vec<_>>>()?);
+
+                let root: ring_utils::Result<Option<_>> = scope.root_project().into();
+                crates.extend(root?.filter(|root| crates.iter().all(|krate| krate.root() != root.root())));
+            }
+            OptionalResult::Fail(err) => return Err(err.into()),
+            OptionalResult::Empty => {}
+        }
+    }
+
+    if crates.is_empty() {
+        for detector in module.project_detectors() {
+            let krate: ring_utils::Result<Option<_>> = detector.detect_from_as(path).into();
+            crates.extend(krate?);
+        }
+    }
+
+    crates.retain(|krate| !core.config().is_ignored(krate.root()));
+    Ok(crates)
+}
+
+fn format_values(values: &[FeatureValue]) -> String {
+    if values.is_empty() {
+        "none".bright_black().to_string()
+    } else {
+        values.iter().join(", ")
+    }
+}
@@ -0,0 +1,19 @@
+mod features;
+
+use clap::{ArgMatches, Command};
+use ring_core::RingCore;
+
+pub fn build_command() -> Command {
+    Command::new("rust")
+        .subcommand_required(true)
+        .subcommands([
+            features::build_command(),
+        ])
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    match args.subcommand() {
+        Some(("features", args)) => features::handle_command(core, args),
+        _ => unreachable!()
+    }
+}
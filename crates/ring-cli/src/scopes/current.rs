@@ -0,0 +1,35 @@
+use std::env;
+use clap::Command;
+use tracing::warn;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_utils::Normalize;
+use crate::scopes::{add_scope_row, report_errors};
+
+pub fn build_command() -> Command {
+    Command::new("current")
+        .visible_alias("pwd")
+}
+
+pub fn handle_command(core: &RingCore) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+
+    let (tree, errors) = core.scope_tree_from(&current_dir);
+    let mut list = ListFormatter::new();
+
+    // Many modules may have a scope at the nearest root
+    if let Some(nearest) = tree.nearest(&current_dir) {
+        for (module, scope) in nearest.scopes() {
+            add_scope_row(&mut list, module, scope, 0)?;
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+    } else if errors.is_empty() {
+        warn!("No matching scope found");
+    }
+
+    report_errors(&errors);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::env;
+use std::path::PathBuf;
+use clap::{arg, ArgMatches, Command, value_parser};
+use tracing::warn;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_utils::{Normalize, OptionalResult};
+use crate::scopes::{add_scope_row, report_errors};
+
+pub fn build_command() -> Command {
+    Command::new("list")
+        .visible_alias("ls")
+        .arg(arg!([path])
+            .value_parser(value_parser!(PathBuf)))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let path = args.get_one::<PathBuf>("path")
+        .map(|path| path.resolve(&current_dir))
+        .unwrap_or(current_dir);
+
+    let (mut tree, mut errors) = core.scope_tree_from(&path);
+
+    // Also list outer scopes containing the nearest ones
+    for ancestor in path.ancestors() {
+        for module in core.modules() {
+            for detector in module.scope_detectors() {
+                match detector.detect_at_as(ancestor) {
+                    OptionalResult::Found(scope) => {
+                        tree.insert(module.name(), scope);
+                    }
+                    OptionalResult::Fail(err) => errors.push(err),
+                    OptionalResult::Empty => continue,
+                }
+            }
+        }
+    }
+
+    let mut list = ListFormatter::new();
+
+    for workspace in tree.iter() {
+        let depth = tree.depth(workspace);
+
+        for (module, scope) in workspace.scopes() {
+            add_scope_row(&mut list, module, scope, depth)?;
+        }
+    }
+
+    if !list.is_empty() {
+        println!("{list}");
+    } else if errors.is_empty() {
+        warn!("No matching scope found");
+    }
+
+    report_errors(&errors);
+    Ok(())
+}
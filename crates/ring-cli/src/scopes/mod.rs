@@ -0,0 +1,61 @@
+mod current;
+mod list;
+
+use std::rc::Rc;
+use clap::{ArgMatches, Command};
+use owo_colors::OwoColorize;
+use tracing::warn;
+use ring_cli_formatters::ListFormatter;
+use ring_core::RingCore;
+use ring_traits::Scope;
+use ring_utils::Error;
+use crate::diagnostics::render_error;
+
+pub fn build_command() -> Command {
+    Command::new("scopes")
+        .aliases(["scope"])
+        .visible_aliases(["scp"])
+        .subcommand_required(true)
+        .subcommands([
+            current::build_command(),
+            list::build_command(),
+        ])
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    match args.subcommand() {
+        Some(("current", _)) => current::handle_command(core),
+        Some(("list", args)) => list::handle_command(core, args),
+        _ => unreachable!()
+    }
+}
+
+/// Adds a row describing given scope, indented by its depth among listed scopes
+fn add_scope_row(list: &mut ListFormatter<4>, module: &str, scope: &Rc<dyn Scope>, depth: usize) -> anyhow::Result<()> {
+    let projects = scope.projects().collect::<ring_utils::Result<Vec<_>>>()?;
+    let package_manager = scope.package_manager_name()
+        .map_or_else(|| "none".bright_black().to_string(), str::to_string);
+
+    list.add_row([
+        &format!("{}{}", "  ".repeat(depth), scope.root().display()),
+        &module,
+        &package_manager,
+        &format!("{} projects", projects.len()).bright_black(),
+    ]);
+
+    Ok(())
+}
+
+/// Prints scopes which could not be loaded, without failing
+fn report_errors(errors: &[Error]) {
+    if errors.is_empty() {
+        return;
+    }
+
+    for err in errors {
+        eprintln!("\n{}", render_error(err));
+    }
+
+    eprintln!();
+    warn!("{} scope(s) could not be loaded", errors.len());
+}
@@ -0,0 +1,20 @@
+use std::env;
+use clap::{arg, ArgMatches, Command};
+use ring_core::RingCore;
+use ring_utils::Normalize;
+use crate::projects::resolve_project;
+
+pub fn build_command() -> Command {
+    Command::new("where")
+        .arg(arg!(<project> "Name of the project"))
+}
+
+pub fn handle_command(core: &RingCore, args: &ArgMatches) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()?.normalize();
+    let name = args.get_one::<String>("project").unwrap();
+
+    let project = resolve_project(core, &current_dir, name)?;
+    println!("{}", project.root().display());
+
+    Ok(())
+}
@@ -50,7 +50,7 @@ pub struct Iter<'a, T> {
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = anyhow::Result<T>;
+    type Item = ring_utils::Result<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -83,10 +83,9 @@ impl<'a, T> FusedIterator for Iter<'a, T> {}
 mod tests {
     use std::path::Path;
     use super::*;
-    use anyhow::anyhow;
     use mockall::mock;
     use ring_traits::DetectAs;
-    use ring_utils::Normalize;
+    use ring_utils::{Error, Normalize};
     use ring_utils::OptionalResult::{Empty, Fail, Found};
 
     mock!(
@@ -120,7 +119,7 @@ mod tests {
 
         let detector_fail = Rc::new({
             let mut detector = MockDetector::new();
-            detector.expect_detect_at_as().returning(|_| Fail(anyhow!("Failed !")));
+            detector.expect_detect_at_as().returning(|_| Fail(Error::config("Failed !")));
             detector
         });
 
@@ -158,7 +157,7 @@ mod tests {
 
         let detector_fail = Rc::new({
             let mut detector = MockDetector::new();
-            detector.expect_detect_from_as().returning(|_| Fail(anyhow!("Failed !")));
+            detector.expect_detect_from_as().returning(|_| Fail(Error::config("Failed !")));
             detector
         });
 
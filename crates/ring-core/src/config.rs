@@ -0,0 +1,433 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use tracing::info;
+use ring_custom::CustomType;
+use ring_files::{FileSystem, GlobPattern, RealFileSystem};
+use ring_traits::Manifest;
+use ring_utils::{Error, ManifestError, Normalize, NormalizedPath, NormalizedPathBuf};
+use crate::DirectoryNameRule;
+
+pub const CONFIG_FILE: &str = "ring.toml";
+
+/// Content of a single `ring.toml` file
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Names of enabled modules (all modules are enabled if missing)
+    #[serde(default)]
+    pub modules: Option<Vec<String>>,
+    /// Glob patterns of paths to ignore
+    #[serde(default)]
+    pub ignore: Vec<String>,
+    /// Glob patterns of additional project roots
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub tasks: BTreeMap<String, TaskConfig>,
+    /// External modules, by name
+    #[serde(default)]
+    pub plugins: BTreeMap<String, PluginConfig>,
+    /// Custom project types, by name
+    #[serde(default)]
+    pub types: BTreeMap<String, CustomType>,
+    #[serde(default)]
+    pub names: NamesConfigFile,
+    #[serde(default)]
+    pub output: OutputConfigFile,
+}
+
+impl Manifest for ConfigFile {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        serde_path_to_error::deserialize(toml::Deserializer::new(content))
+            .map_err(|err| {
+                let inner = err.inner();
+                let error = ManifestError::new(inner.message())
+                    .with_key(err.path().to_string());
+
+                match inner.span() {
+                    Some(span) => error.with_offset(content, span.start),
+                    None => error,
+                }.into()
+            })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TaskConfig {
+    pub command: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PluginConfig {
+    /// Plugin executable, relative paths are resolved from the directory containing the config file
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NamesConfigFile {
+    /// Rule project directory names should follow
+    #[serde(default)]
+    pub directory: Option<DirectoryNameRule>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfigFile {
+    #[serde(default)]
+    pub verbose: Option<u8>,
+}
+
+/// Origin of a configuration value
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ConfigSource {
+    #[default]
+    Default,
+    User(NormalizedPathBuf),
+    Project(NormalizedPathBuf),
+}
+
+impl ConfigSource {
+    /// Returns path of source file (if any)
+    pub fn path(&self) -> Option<&NormalizedPath> {
+        match self {
+            ConfigSource::Default => None,
+            ConfigSource::User(path) | ConfigSource::Project(path) => Some(path),
+        }
+    }
+
+    /// Resolves a glob pattern written in this source, relative to the directory containing it.
+    ///
+    /// Returns `None` for relative patterns written in the user config file.
+    fn resolve_pattern(&self, pattern: &str) -> Option<NormalizedPathBuf> {
+        let pattern = Path::new(pattern);
+
+        match self {
+            ConfigSource::Project(path) => Some(pattern.resolve(path.parent().unwrap_or(path))),
+            _ if pattern.is_absolute() => Some(pattern.normalize()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::User(path) | ConfigSource::Project(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Value with the source it comes from
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: ConfigSource,
+}
+
+impl<T> Sourced<T> {
+    pub fn new(value: T, source: ConfigSource) -> Sourced<T> {
+        Sourced { value, source }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NamesConfig {
+    pub directory: Sourced<DirectoryNameRule>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct OutputConfig {
+    pub verbose: Sourced<u8>,
+}
+
+/// Effective configuration, merged from every loaded config file
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// Loaded sources, from lowest to highest priority
+    pub sources: Vec<ConfigSource>,
+    pub modules: Sourced<Option<Vec<String>>>,
+    pub ignore: Vec<Sourced<GlobPattern>>,
+    pub projects: Vec<Sourced<NormalizedPathBuf>>,
+    pub tasks: BTreeMap<String, Sourced<TaskConfig>>,
+    pub plugins: BTreeMap<String, Sourced<PluginConfig>>,
+    pub types: BTreeMap<String, Sourced<CustomType>>,
+    pub names: NamesConfig,
+    pub output: OutputConfig,
+}
+
+impl Config {
+    /// Loads user config file, then every `ring.toml` from the root to given path.
+    ///
+    /// Nearest files take precedence, ignore and project patterns are accumulated.
+    pub fn load(path: &NormalizedPath) -> ring_utils::Result<Config> {
+        Config::load_from(&RealFileSystem, path)
+    }
+
+    /// Same as [`Config::load`], but reads `ring.toml` files from given file system.
+    /// User config file is not part of any project, so it is always read from disk.
+    pub fn load_from(fs: &dyn FileSystem, path: &NormalizedPath) -> ring_utils::Result<Config> {
+        let mut config = Config::default();
+
+        if let Some(user_config) = user_config_path().filter(|path| RealFileSystem.is_file(path)) {
+            config.load_file(&RealFileSystem, ConfigSource::User(user_config))?;
+        }
+
+        let files = path.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .filter(|file| fs.is_file(file))
+            .collect::<Vec<_>>();
+
+        for file in files.into_iter().rev() {
+            config.load_file(fs, ConfigSource::Project(file))?;
+        }
+
+        Ok(config)
+    }
+
+    fn load_file(&mut self, fs: &dyn FileSystem, source: ConfigSource) -> ring_utils::Result<()> {
+        let path = source.path().expect("Config files should have a path");
+        info!("Loading config from {}", path.display());
+
+        let content = fs.read_to_string(path)
+            .map_err(|err| Error::io(path, err))?;
+        let file = ConfigFile::from_str(&content)
+            .map_err(|err| match err {
+                Error::Manifest(err) => err.with_path(path.to_path_buf()).with_content(content).into(),
+                err => err,
+            })?;
+
+        self.merge(file, source)
+    }
+
+    /// Merges given config file over current configuration
+    pub fn merge(&mut self, file: ConfigFile, source: ConfigSource) -> ring_utils::Result<()> {
+        if let Some(modules) = file.modules {
+            self.modules = Sourced::new(Some(modules), source.clone());
+        }
+
+        for pattern in &file.ignore {
+            // Relative user patterns match anywhere
+            let resolved = match source.resolve_pattern(pattern) {
+                Some(resolved) => resolved.display().to_string(),
+                None => format!("**/{pattern}"),
+            };
+
+            self.ignore.push(Sourced::new(GlobPattern::new(&resolved), source.clone()));
+        }
+
+        for pattern in &file.projects {
+            let resolved = source.resolve_pattern(pattern)
+                .ok_or_else(|| Error::config(format!("Project pattern {pattern} in {source} must be absolute")))?;
+
+            self.projects.push(Sourced::new(resolved, source.clone()));
+        }
+
+        for (name, task) in file.tasks {
+            self.tasks.insert(name, Sourced::new(task, source.clone()));
+        }
+
+        for (name, mut plugin) in file.plugins {
+            let command = Path::new(&plugin.command);
+
+            // Bare commands are searched in PATH
+            if command.is_relative() && command.components().count() > 1 {
+                if let Some(dir) = source.path().and_then(NormalizedPath::parent) {
+                    plugin.command = dir.join(command).display().to_string();
+                }
+            }
+
+            self.plugins.insert(name, Sourced::new(plugin, source.clone()));
+        }
+
+        for (name, project_type) in file.types {
+            self.types.insert(name, Sourced::new(project_type, source.clone()));
+        }
+
+        if let Some(directory) = file.names.directory {
+            self.names.directory = Sourced::new(directory, source.clone());
+        }
+
+        if let Some(verbose) = file.output.verbose {
+            self.output.verbose = Sourced::new(verbose, source.clone());
+        }
+
+        self.sources.push(source);
+
+        Ok(())
+    }
+
+    /// Returns `true` if given path, or one of its ancestors, matches an ignore pattern
+    pub fn is_ignored(&self, path: &NormalizedPath) -> bool {
+        self.ignore.iter()
+            .any(|pattern| path.ancestors().any(|ancestor| pattern.value.matches(ancestor.as_path())))
+    }
+}
+
+/// Location of the user config file, following XDG conventions
+fn user_config_path() -> Option<NormalizedPathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME").map(PathBuf::from)
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("ring").join(CONFIG_FILE).normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_source(path: &str) -> ConfigSource {
+        ConfigSource::Project(Path::new(path).normalize())
+    }
+
+    #[test]
+    fn it_should_parse_config_file() {
+        let file = ConfigFile::from_str(r#"
+modules = ["js"]
+ignore = ["examples/**"]
+projects = ["tools/*"]
+
+[tasks.build]
+command = "cargo build"
+description = "Builds everything"
+
+[names]
+directory = "unscoped"
+
+[output]
+verbose = 1
+"#).unwrap();
+
+        assert_eq!(file, ConfigFile {
+            modules: Some(vec!["js".to_string()]),
+            ignore: vec!["examples/**".to_string()],
+            projects: vec!["tools/*".to_string()],
+            tasks: BTreeMap::from([
+                ("build".to_string(), TaskConfig {
+                    command: "cargo build".to_string(),
+                    description: Some("Builds everything".to_string()),
+                }),
+            ]),
+            plugins: BTreeMap::new(),
+            types: BTreeMap::new(),
+            names: NamesConfigFile { directory: Some(DirectoryNameRule::Unscoped) },
+            output: OutputConfigFile { verbose: Some(1) },
+        });
+    }
+
+    #[test]
+    fn it_should_resolve_plugin_commands() {
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str(r#"
+[plugins.bazel]
+command = "ring-module-bazel"
+
+[plugins.stub]
+command = "tools/stub"
+args = ["--verbose"]
+priority = 10
+"#).unwrap(), project_source("/repo/ring.toml")).unwrap();
+
+        assert_eq!(config.plugins["bazel"].value.command, "ring-module-bazel");
+        assert_eq!(Path::new(&config.plugins["stub"].value.command), Path::new("/repo/tools/stub"));
+        assert_eq!(config.plugins["stub"].value.priority, Some(10));
+    }
+
+    #[test]
+    fn it_should_parse_project_types() {
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str(r#"
+[types.helm]
+marker = "Chart.yaml"
+name = "name"
+version = "version"
+color = "blue"
+members = ["charts/*"]
+
+[types.docker]
+marker = "Dockerfile"
+"#).unwrap(), project_source("/repo/ring.toml")).unwrap();
+
+        assert_eq!(config.types["helm"].value, CustomType {
+            marker: "Chart.yaml".to_string(),
+            format: None,
+            name_field: Some("name".to_string()),
+            version_field: Some("version".to_string()),
+            tag: None,
+            color: Some("blue".to_string()),
+            members: vec!["charts/*".to_string()],
+            priority: None,
+        });
+        assert_eq!(config.types["docker"].value.marker, "Dockerfile");
+        assert_eq!(config.types["docker"].source, project_source("/repo/ring.toml"));
+    }
+
+    #[test]
+    fn it_should_reject_unknown_keys() {
+        assert!(ConfigFile::from_str("modlues = [\"js\"]").is_err());
+    }
+
+    #[test]
+    fn it_should_let_nearest_file_take_precedence() {
+        let root = project_source("/repo/ring.toml");
+        let nested = project_source("/repo/app/ring.toml");
+
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str("modules = [\"js\", \"rust\"]\n[output]\nverbose = 2").unwrap(), root.clone()).unwrap();
+        config.merge(ConfigFile::from_str("modules = [\"js\"]").unwrap(), nested.clone()).unwrap();
+
+        assert_eq!(config.modules, Sourced::new(Some(vec!["js".to_string()]), nested.clone()));
+        assert_eq!(config.output.verbose, Sourced::new(2, root.clone()));
+        assert_eq!(config.sources, vec![root, nested]);
+    }
+
+    #[test]
+    fn it_should_resolve_patterns_from_config_file_directory() {
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str("ignore = [\"examples\"]\nprojects = [\"tools/*\"]").unwrap(), project_source("/repo/ring.toml")).unwrap();
+
+        assert_eq!(config.projects[0].value, Path::new("/repo/tools/*").normalize());
+        assert!(config.is_ignored(&Path::new("/repo/examples").normalize()));
+        assert!(config.is_ignored(&Path::new("/repo/examples/basic").normalize()));
+        assert!(!config.is_ignored(&Path::new("/repo/crates/examples").normalize()));
+    }
+
+    #[test]
+    fn it_should_match_user_patterns_anywhere() {
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str("ignore = [\"fixtures\"]").unwrap(), ConfigSource::User(Path::new("/home/user/.config/ring/ring.toml").normalize())).unwrap();
+
+        assert!(config.is_ignored(&Path::new("/repo/crates/fixtures/app").normalize()));
+        assert!(!config.is_ignored(&Path::new("/repo/crates/app").normalize()));
+    }
+
+    #[test]
+    fn it_should_reject_relative_user_project_patterns() {
+        let mut config = Config::default();
+        let result = config.merge(ConfigFile::from_str("projects = [\"tools/*\"]").unwrap(), ConfigSource::User(Path::new("/home/user/.config/ring/ring.toml").normalize()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_match_unclosed_classes_literally() {
+        let mut config = Config::default();
+        config.merge(ConfigFile::from_str("ignore = [\"[a\"]").unwrap(), project_source("/repo/ring.toml")).unwrap();
+
+        assert!(config.is_ignored(&Path::new("/repo/[a").normalize()));
+        assert!(!config.is_ignored(&Path::new("/repo/a").normalize()));
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, VecDeque};
+use ring_traits::{Dependency, ResolvedPackage};
+
+struct Walk {
+    order: Vec<usize>,
+    parents: Vec<Option<usize>>,
+    visited: Vec<bool>,
+}
+
+/// Graph of resolved packages, linked by their dependencies
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    packages: Vec<ResolvedPackage>,
+    edges: Vec<Vec<usize>>,
+    by_name: HashMap<String, Vec<usize>>,
+}
+
+impl DependencyGraph {
+    pub fn new(packages: Vec<ResolvedPackage>) -> DependencyGraph {
+        let mut by_name: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (idx, package) in packages.iter().enumerate() {
+            by_name.entry(package.name.clone()).or_default().push(idx);
+        }
+
+        let edges = packages.iter()
+            .map(|package| package.dependencies.iter()
+                .flat_map(|reference| by_name.get(&reference.name)
+                    .into_iter()
+                    .flatten()
+                    .copied()
+                    .filter(|&idx| packages[idx].matches(reference))
+                )
+                .collect()
+            )
+            .collect();
+
+        DependencyGraph { packages, edges, by_name }
+    }
+
+    /// Returns every package with given name
+    pub fn packages_named<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a ResolvedPackage> + 'a {
+        self.by_name.get(name)
+            .into_iter()
+            .flatten()
+            .map(|&idx| &self.packages[idx])
+    }
+
+    /// Returns every package satisfying given dependency
+    pub fn resolve(&self, dependency: &Dependency) -> Vec<&ResolvedPackage> {
+        self.resolve_indexes(dependency).into_iter()
+            .map(|idx| &self.packages[idx])
+            .collect()
+    }
+
+    fn resolve_indexes(&self, dependency: &Dependency) -> Vec<usize> {
+        self.by_name.get(dependency.name())
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&idx| dependency.accepts(&self.packages[idx].version))
+            .collect()
+    }
+
+    /// Returns the shortest dependency chain from packages satisfying `from` to each package named `to`.
+    ///
+    /// Each chain starts with a `from` package and ends with a `to` package.
+    pub fn chains(&self, from: &Dependency, to: &str) -> Vec<Vec<&ResolvedPackage>> {
+        let walk = self.walk(self.resolve_indexes(from).into_iter(), |package| package.name != to);
+
+        walk.order.iter()
+            .filter(|&&idx| self.packages[idx].name == to)
+            .map(|&idx| self.chain_to(idx, &walk.parents))
+            .collect()
+    }
+
+    /// Returns every package that cannot be reached from given roots
+    pub fn unreachable<'a>(&'a self, roots: impl IntoIterator<Item = &'a ResolvedPackage>) -> Vec<&'a ResolvedPackage> {
+        let roots = roots.into_iter()
+            .filter_map(|root| self.packages.iter().position(|package| std::ptr::eq(package, root)));
+
+        let walk = self.walk(roots, |_| true);
+
+        self.packages.iter()
+            .zip(walk.visited)
+            .filter(|(_, visited)| !visited)
+            .map(|(package, _)| package)
+            .collect()
+    }
+
+    /// Breadth first walk from given packages, following dependencies of packages accepted by `follow`
+    fn walk(&self, starts: impl Iterator<Item = usize>, follow: impl Fn(&ResolvedPackage) -> bool) -> Walk {
+        let mut walk = Walk {
+            order: Vec::new(),
+            parents: vec![None; self.packages.len()],
+            visited: vec![false; self.packages.len()],
+        };
+        let mut queue = VecDeque::new();
+
+        for idx in starts {
+            if !walk.visited[idx] {
+                walk.visited[idx] = true;
+                queue.push_back(idx);
+            }
+        }
+
+        while let Some(idx) = queue.pop_front() {
+            walk.order.push(idx);
+
+            if !follow(&self.packages[idx]) {
+                continue;
+            }
+
+            for &child in &self.edges[idx] {
+                if !walk.visited[child] {
+                    walk.visited[child] = true;
+                    walk.parents[child] = Some(idx);
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        walk
+    }
+
+    fn chain_to(&self, mut idx: usize, parents: &[Option<usize>]) -> Vec<&ResolvedPackage> {
+        let mut chain = vec![&self.packages[idx]];
+
+        while let Some(parent) = parents[idx] {
+            chain.push(&self.packages[parent]);
+            idx = parent;
+        }
+
+        chain.reverse();
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring_traits::{DependencyKind, PackageRef};
+    use semver::VersionReq;
+
+    fn dependency(name: &str, req: Option<&str>) -> Dependency {
+        Dependency::new(name.to_string(), req.unwrap_or("*").to_string(), DependencyKind::Runtime)
+            .with_version_reqs(req.map(|req| VersionReq::parse(req).unwrap()).into_iter().collect())
+    }
+
+    fn package(name: &str, version: &str, dependencies: &[(&str, Option<&str>)]) -> ResolvedPackage {
+        ResolvedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            dependencies: dependencies.iter()
+                .map(|(name, version)| PackageRef::new(name.to_string(), version.map(str::to_string)))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_should_find_shortest_chain_to_package() {
+        let graph = DependencyGraph::new(vec![
+            package("react", "18.3.1", &[("loose-envify", None)]),
+            package("loose-envify", "1.4.0", &[("js-tokens", None)]),
+            package("js-tokens", "4.0.0", &[]),
+            package("react-dom", "18.3.1", &[("react", None), ("js-tokens", None)]),
+        ]);
+
+        let chains = graph.chains(&dependency("react", None), "js-tokens");
+        assert_eq!(chains.len(), 1);
+        assert_eq!(
+            chains[0].iter().map(|pkg| pkg.to_string()).collect::<Vec<_>>(),
+            ["react@18.3.1", "loose-envify@1.4.0", "js-tokens@4.0.0"],
+        );
+
+        let chains = graph.chains(&dependency("react-dom", None), "js-tokens");
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].len(), 2);
+    }
+
+    #[test]
+    fn it_should_find_one_chain_per_version() {
+        let graph = DependencyGraph::new(vec![
+            package("a", "1.0.0", &[("b", None), ("c", Some("2.0.0"))]),
+            package("b", "1.0.0", &[("c", Some("1.0.0"))]),
+            package("c", "1.0.0", &[]),
+            package("c", "2.0.0", &[]),
+        ]);
+
+        let chains = graph.chains(&dependency("a", None), "c");
+        assert_eq!(chains.len(), 2);
+        assert_eq!(chains[0].last().unwrap().version, "2.0.0");
+        assert_eq!(chains[1].last().unwrap().version, "1.0.0");
+    }
+
+    #[test]
+    fn it_should_return_nothing_for_unrelated_packages() {
+        let graph = DependencyGraph::new(vec![
+            package("a", "1.0.0", &[]),
+            package("b", "1.0.0", &[]),
+        ]);
+
+        assert!(graph.chains(&dependency("a", None), "b").is_empty());
+        assert!(graph.chains(&dependency("unknown", None), "b").is_empty());
+        assert_eq!(graph.packages_named("a").count(), 1);
+    }
+
+    #[test]
+    fn it_should_start_chains_from_versions_satisfying_dependency() {
+        let graph = DependencyGraph::new(vec![
+            package("eslint", "8.57.1", &[("chalk", None)]),
+            package("eslint", "9.9.0", &[]),
+            package("chalk", "4.1.2", &[]),
+        ]);
+
+        assert!(graph.chains(&dependency("eslint", Some("^9.0.0")), "chalk").is_empty());
+        assert_eq!(graph.chains(&dependency("eslint", Some("^8.0.0")), "chalk").len(), 1);
+        assert_eq!(graph.resolve(&dependency("eslint", Some("^9.0.0"))).len(), 1);
+    }
+
+    #[test]
+    fn it_should_list_unreachable_packages() {
+        let graph = DependencyGraph::new(vec![
+            package("a", "1.0.0", &[("b", None)]),
+            package("b", "1.0.0", &[]),
+            package("c", "1.0.0", &[("b", None)]),
+        ]);
+
+        let root = graph.packages_named("a").collect::<Vec<_>>();
+        let unreachable = graph.unreachable(root);
+
+        assert_eq!(unreachable.len(), 1);
+        assert_eq!(unreachable[0].name, "c");
+    }
+}
@@ -0,0 +1,127 @@
+use crate::RingCore;
+use ring_files::Walker;
+use ring_traits::Project;
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use tracing::debug;
+
+/// Project found while walking a directory tree
+pub struct DiscoveredProject {
+    pub project: Rc<dyn Project>,
+
+    /// `true` if no scope declares the project, either as root or as member
+    pub orphan: bool,
+}
+
+impl RingCore {
+    /// Walks every directory under given path in core file system, and runs project detectors on
+    /// each of them.
+    ///
+    /// Walk respects ignore files and ring's ignore patterns, and skips hidden, `node_modules`
+    /// and `target` directories.
+    pub fn discover_projects(&self, path: &NormalizedPath) -> ring_utils::Result<Vec<DiscoveredProject>> {
+        let project_detector = self.project_detector();
+        let scope_detector = self.scope_detector();
+
+        let mut projects = Vec::new();
+        let mut declared = BTreeSet::<NormalizedPathBuf>::new();
+
+        for dir in Walker::new(self.fs.clone(), path) {
+            let dir = dir?;
+
+            if self.config.is_ignored(&dir) {
+                continue;
+            }
+
+            debug!("Discover projects in {}", dir.display());
+
+            for scope in scope_detector.detect_at(&dir) {
+                let scope = scope?;
+                let root: ring_utils::Result<Option<_>> = scope.root_project().into();
+
+                if let Some(root) = root? {
+                    declared.insert(root.root().to_path_buf());
+                }
+
+                for member in scope.projects() {
+                    declared.insert(member?.root().to_path_buf());
+                }
+            }
+
+            for project in project_detector.detect_at(&dir) {
+                projects.push(project?);
+            }
+        }
+
+        Ok(projects.into_iter()
+            .map(|project| DiscoveredProject {
+                orphan: !declared.contains(project.root()),
+                project,
+            })
+            .collect())
+    }
+}
+
+#[cfg(all(test, feature = "js"))]
+mod tests {
+    use std::{env, fs};
+    use std::path::Path;
+    use ring_files::MemoryFileSystem;
+    use ring_utils::Normalize;
+    use crate::Config;
+    use super::*;
+
+    #[test]
+    fn it_should_discover_orphan_projects() {
+        let dir = env::temp_dir().join(format!("ring-core-discovery-{}", std::process::id())).normalize();
+        fs::create_dir_all(dir.join("packages/api")).unwrap();
+        fs::create_dir_all(dir.join("tools/script")).unwrap();
+        fs::create_dir_all(dir.join("node_modules/dep")).unwrap();
+        fs::create_dir_all(dir.join("build/out")).unwrap();
+        fs::write(dir.join("package.json"), r#"{ "name": "root", "workspaces": ["packages/*"] }"#).unwrap();
+        fs::write(dir.join("packages/api/package.json"), r#"{ "name": "api" }"#).unwrap();
+        fs::write(dir.join("tools/script/package.json"), r#"{ "name": "script" }"#).unwrap();
+        fs::write(dir.join("node_modules/dep/package.json"), r#"{ "name": "dep" }"#).unwrap();
+        fs::write(dir.join("build/out/package.json"), r#"{ "name": "out" }"#).unwrap();
+        fs::write(dir.join(".gitignore"), "build\n").unwrap();
+
+        let result = RingCore::new().discover_projects(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut projects = result.unwrap().into_iter()
+            .map(|discovered| (discovered.project.name().to_string(), discovered.orphan))
+            .collect::<Vec<_>>();
+        projects.sort();
+
+        assert_eq!(projects, [
+            ("api".to_string(), false),
+            ("root".to_string(), false),
+            ("script".to_string(), true),
+        ]);
+    }
+
+    #[test]
+    fn it_should_discover_projects_in_core_file_system() {
+        let root = Path::new("/repo").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("package.json"), r#"{ "name": "root", "workspaces": ["packages/*"] }"#);
+        fs.add_file(&root.join("packages/api/package.json"), r#"{ "name": "api" }"#);
+        fs.add_file(&root.join("tools/script/package.json"), r#"{ "name": "script" }"#);
+        fs.add_file(&root.join("build/out/package.json"), r#"{ "name": "out" }"#);
+        fs.add_file(&root.join(".gitignore"), "build\n");
+
+        let core = RingCore::with_file_system(Config::default(), Rc::new(fs)).unwrap();
+
+        let mut projects = core.discover_projects(&root).unwrap().into_iter()
+            .map(|discovered| (discovered.project.name().to_string(), discovered.orphan))
+            .collect::<Vec<_>>();
+        projects.sort();
+
+        assert_eq!(projects, [
+            ("api".to_string(), false),
+            ("root".to_string(), false),
+            ("script".to_string(), true),
+        ]);
+    }
+}
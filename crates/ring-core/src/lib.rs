@@ -1,6 +1,21 @@
 pub use combined_detector::CombinedDetector;
+pub use config::{Config, ConfigFile, ConfigSource, CONFIG_FILE, NamesConfig, NamesConfigFile, OutputConfig, OutputConfigFile, PluginConfig, Sourced, TaskConfig};
+pub use dependency_graph::DependencyGraph;
+pub use discovery::DiscoveredProject;
+pub use lockfile_check::{check_lockfile, LockfileIssue};
+pub use module_registry::{DEFAULT_PRIORITY, ModuleRegistry};
+pub use name_check::{check_names, DirectoryNameRule, NameIssue};
+pub use project_resolver::{NameMatch, ProjectResolver, Resolution};
+pub use scope_tree::{ScopeTree, Workspace};
+use ring_files::{FileSystem, GitFileSystem, PatternIterator, RealFileSystem};
 use ring_traits::{Module, Project, Scope, Tagged};
+use ring_utils::{Context, Error, NormalizedPath, OptionalResult, PartialResult};
+use std::collections::BTreeSet;
 use std::rc::Rc;
+use tracing::info;
+
+#[cfg(feature = "custom")]
+use ring_custom::CustomModule;
 
 #[cfg(feature = "js")]
 use ring_js::JsModule;
@@ -8,24 +23,283 @@ use ring_js::JsModule;
 #[cfg(feature = "rust")]
 use ring_rust::RustModule;
 
+#[cfg(feature = "plugin")]
+use ring_plugin::PluginModule;
+
 mod combined_detector;
+mod config;
+mod dependency_graph;
+mod discovery;
+mod lockfile_check;
+mod module_registry;
+mod name_check;
+mod project_resolver;
+mod scope_tree;
+
+#[cfg(test)]
+mod test_utils;
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct RingCore {
-    #[cfg(feature = "js")]   js_module: JsModule,
-    #[cfg(feature = "rust")] rust_module: RustModule,
+    config: Config,
+    registry: ModuleRegistry,
+    fs: Rc<dyn FileSystem>,
 }
 
 impl RingCore {
+    /// Builds a core with default configuration, and every built-in module
     pub fn new() -> RingCore {
-        Default::default()
+        RingCore::with_builtin_modules(Config::default(), Rc::new(RealFileSystem))
     }
 
+    fn with_builtin_modules(config: Config, fs: Rc<dyn FileSystem>) -> RingCore {
+        let builtins: Vec<Box<dyn Module>> = vec![
+            #[cfg(feature = "js")]   Box::new(JsModule::with_fs(fs.clone())),
+            #[cfg(feature = "rust")] Box::new(RustModule::with_fs(fs.clone())),
+        ];
+
+        let mut registry = ModuleRegistry::new();
+
+        for module in builtins {
+            registry.register(module);
+        }
+
+        RingCore { config, registry, fs }
+    }
+
+    /// Builds a core with every built-in module, plugins found in `PATH`, plugins and project types
+    /// declared in configuration, enabled according to given configuration
+    pub fn with_config(config: Config) -> ring_utils::Result<RingCore> {
+        let mut core = RingCore::with_builtin_modules(config, Rc::new(RealFileSystem));
+
+        #[cfg(feature = "plugin")]
+        core.register_plugins();
+
+        core.setup_from_config()?;
+        Ok(core)
+    }
+
+    /// Builds a core reading projects from given file system, with every built-in module and
+    /// project types declared in configuration.
+    ///
+    /// Plugins read files on their own, so they are not registered.
+    pub fn with_file_system(config: Config, fs: Rc<dyn FileSystem>) -> ring_utils::Result<RingCore> {
+        let mut core = RingCore::with_builtin_modules(config, fs);
+
+        core.setup_from_config()?;
+        Ok(core)
+    }
+
+    /// Builds a core reading projects of the git repository containing given path, as they were
+    /// at given revision. Configuration files are read at this revision too, while enabled modules
+    /// are the same as this core.
+    pub fn at_revision(&self, path: &NormalizedPath, revision: &str) -> ring_utils::Result<RingCore> {
+        let fs = GitFileSystem::new(path, revision)?;
+        info!("Reading projects at {} ({})", revision, fs.commit());
+
+        let config = Config::load_from(&fs, path)?;
+        let mut core = RingCore::with_file_system(config, Rc::new(fs))?;
+
+        for (module, enabled) in self.registry.all_modules() {
+            core.registry.set_enabled(module.name(), enabled);
+        }
+
+        Ok(core)
+    }
+
+    fn setup_from_config(&mut self) -> ring_utils::Result<()> {
+        #[cfg(feature = "custom")]
+        self.register_custom_types()?;
+
+        if let Some(modules) = &self.config.modules.value {
+            self.registry.enable_only(modules)
+                .with_context(|| format!("Invalid modules in {}", self.config.modules.source))?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "plugin")]
+    fn register_plugins(&mut self) {
+        for (name, program) in ring_plugin::find_plugins() {
+            self.registry.register(Box::new(PluginModule::new(name, program, Vec::new())));
+        }
+
+        for (name, plugin) in &self.config.plugins {
+            let module = PluginModule::new(name.clone(), plugin.value.command.clone().into(), plugin.value.args.clone());
+            self.registry.register_with_priority(Box::new(module), plugin.value.priority.unwrap_or(DEFAULT_PRIORITY));
+        }
+    }
+
+    #[cfg(feature = "custom")]
+    fn register_custom_types(&mut self) -> ring_utils::Result<()> {
+        for (name, project_type) in &self.config.types {
+            let priority = project_type.value.priority.unwrap_or(DEFAULT_PRIORITY);
+            let module = CustomModule::with_fs(name.clone(), project_type.value.clone(), self.fs.clone())
+                .with_context(|| format!("Invalid project type {name} in {}", project_type.source))?;
+
+            self.registry.register_with_priority(Box::new(module), priority);
+        }
+
+        Ok(())
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// File system projects are read from
+    pub fn fs(&self) -> &Rc<dyn FileSystem> {
+        &self.fs
+    }
+
+    pub fn registry(&self) -> &ModuleRegistry {
+        &self.registry
+    }
+
+    pub fn registry_mut(&mut self) -> &mut ModuleRegistry {
+        &mut self.registry
+    }
+
+    /// Returns enabled modules, ordered by priority
     pub fn modules(&self) -> Vec<&dyn Module> {
-        vec![
-            #[cfg(feature = "js")]   &self.js_module,
-            #[cfg(feature = "rust")] &self.rust_module,
-        ]
+        self.registry.modules()
+    }
+
+    /// Searches projects matching patterns listed in configuration
+    pub fn configured_projects(&self) -> impl Iterator<Item = ring_utils::Result<Rc<dyn Project>>> + '_ {
+        let detector = self.project_detector();
+
+        self.config.projects.iter()
+            .map(|pattern| &pattern.value)
+            .glob_search(self.fs.clone())
+            .flat_map(move |path| match path {
+                Ok(path) => detector.detect_at(&path).collect::<Vec<_>>(),
+                Err(err) => vec![Err(err)],
+            })
+    }
+
+    /// Lists projects of the nearest scope of each module containing given path, and of scopes
+    /// nested in them (with their root project), followed by configured projects.
+    /// Ignored projects are skipped, and each project is returned once.
+    pub fn projects_from(&self, path: &NormalizedPath) -> ring_utils::Result<Vec<Rc<dyn Project>>> {
+        self.collect_projects_from(path).into_result()
+    }
+
+    /// Same as [`RingCore::projects_from`], but keeps searching when a scope or a project fails
+    /// to load. Returns found projects with every error met.
+    pub fn collect_projects_from(&self, path: &NormalizedPath) -> PartialResult<Rc<dyn Project>> {
+        let mut result = PartialResult::new();
+        let mut known = BTreeSet::new();
+
+        let (tree, errors) = self.scope_tree_from(path);
+
+        for err in errors {
+            result.push_err(err);
+        }
+
+        let projects = tree.iter()
+            .flat_map(|workspace| {
+                // Root projects of nested workspaces are not always members of their parent
+                let roots = workspace.scopes().iter()
+                    .filter(|_| tree.parent(workspace).is_some())
+                    .filter_map(|(_, scope)| Option::from(scope.root_project()));
+
+                roots.chain(workspace.projects()).collect::<Vec<_>>()
+            })
+            .chain(self.configured_projects());
+
+        for project in projects {
+            match project {
+                Ok(project) => {
+                    // Co-located projects of different modules share a root, but not a manifest
+                    let key = (project.root().to_path_buf(), project.manifest_path());
+
+                    if !self.config.is_ignored(project.root()) && known.insert(key) {
+                        result.push(Ok(project));
+                    }
+                }
+                Err(err) => result.push_err(err),
+            }
+        }
+
+        result
+    }
+
+    /// Builds the hierarchy of the nearest scope of each module containing given path, and of
+    /// scopes found at the root of their projects. Scopes sharing a root are merged in one workspace.
+    /// Returns the scopes which could be loaded, with every error met.
+    pub fn scope_tree_from(&self, path: &NormalizedPath) -> (ScopeTree, Vec<Error>) {
+        let mut tree = ScopeTree::new();
+        let mut errors = Vec::new();
+        let mut pending = Vec::new();
+
+        for module in self.modules() {
+            for detector in module.scope_detectors() {
+                match detector.detect_from_as(path) {
+                    OptionalResult::Found(scope) => {
+                        if tree.insert(module.name(), scope.clone()) {
+                            pending.push(scope);
+                        }
+                    }
+                    OptionalResult::Fail(err) => errors.push(err),
+                    OptionalResult::Empty => continue,
+                }
+            }
+        }
+
+        while let Some(scope) = pending.pop() {
+            // Project errors are reported when listing projects
+            let members = scope.projects()
+                .filter_map(Result::ok)
+                .filter(|project| project.root() != scope.root());
+
+            for member in members {
+                for module in self.modules() {
+                    for detector in module.scope_detectors() {
+                        match detector.detect_at_as(member.root()) {
+                            OptionalResult::Found(nested) => {
+                                if tree.insert(module.name(), nested.clone()) {
+                                    pending.push(nested);
+                                }
+                            }
+                            OptionalResult::Fail(err) => errors.push(err),
+                            OptionalResult::Empty => continue,
+                        }
+                    }
+                }
+            }
+        }
+
+        (tree, errors)
+    }
+
+    /// Searches the scope containing given project, either as root project or as member
+    pub fn find_scope(&self, project: &Rc<dyn Project>) -> ring_utils::Result<Option<Rc<dyn Scope>>> {
+        for scope in self.scope_detector().detect_from(&project.root()) {
+            let scope = scope?;
+
+            let root: ring_utils::Result<Option<_>> = scope.root_project().into();
+
+            if root?.is_some_and(|root| root.root() == project.root()) {
+                return Ok(Some(scope));
+            }
+
+            let mut contains_project = false;
+
+            for member in scope.projects() {
+                if member?.root() == project.root() {
+                    contains_project = true;
+                    break;
+                }
+            }
+
+            if contains_project {
+                return Ok(Some(scope));
+            }
+        }
+
+        Ok(None)
     }
 
     pub fn project_detector(&self) -> CombinedDetector<Rc<dyn Project>> {
@@ -52,3 +326,68 @@ impl RingCore {
         )
     }
 }
+
+impl Default for RingCore {
+    fn default() -> Self {
+        RingCore::new()
+    }
+}
+
+#[cfg(all(test, feature = "js"))]
+mod tests {
+    use std::path::Path;
+    use ring_files::MemoryFileSystem;
+    use ring_utils::{ErrorKind, Normalize};
+    use super::*;
+
+    #[test]
+    fn it_should_collect_projects_despite_invalid_members() {
+        let root = Path::new("/repo").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("package.json"), r#"{ "name": "root", "workspaces": ["packages/*"] }"#);
+        fs.add_file(&root.join("packages/api/package.json"), r#"{ "name": "api" }"#);
+        fs.add_file(&root.join("packages/web/package.json"), r#"{ "name": 42 }"#);
+
+        let core = RingCore::with_file_system(Config::default(), Rc::new(fs)).unwrap();
+        let result = core.collect_projects_from(&root);
+
+        let names = result.values().iter().map(|project| project.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["api"]);
+        assert_eq!(result.errors().len(), 1);
+        assert_eq!(result.errors()[0].kind(), ErrorKind::Manifest);
+        assert!(core.projects_from(&root).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rust")]
+    fn it_should_list_projects_of_co_located_and_nested_scopes_once() {
+        let root = Path::new("/repo").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("package.json"), r#"{ "name": "root", "workspaces": ["packages/*"] }"#);
+        fs.add_file(&root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n");
+        fs.add_file(&root.join("packages/api/package.json"), r#"{ "name": "api" }"#);
+        fs.add_file(&root.join("crates/core/Cargo.toml"), "[package]\nname = \"core\"\n");
+        fs.add_file(&root.join("crates/web/Cargo.toml"), "[package]\nname = \"web\"\n");
+        fs.add_file(&root.join("crates/web/package.json"), r#"{ "name": "web-apps", "workspaces": ["apps/*"] }"#);
+        fs.add_file(&root.join("crates/web/apps/ui/package.json"), r#"{ "name": "ui" }"#);
+
+        let core = RingCore::with_file_system(Config::default(), Rc::new(fs)).unwrap();
+
+        let (tree, errors) = core.scope_tree_from(&root);
+        assert!(errors.is_empty());
+
+        let workspaces = tree.iter()
+            .map(|ws| (ws.root().display().to_string(), ws.scopes().len(), tree.depth(ws)))
+            .collect::<Vec<_>>();
+        assert_eq!(workspaces, [
+            ("/repo".to_string(), 2, 0),
+            ("/repo/crates/web".to_string(), 1, 1),
+        ]);
+
+        let mut names = core.projects_from(&root).unwrap().iter()
+            .map(|project| project.name().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["api", "core", "ui", "web", "web-apps"]);
+    }
+}
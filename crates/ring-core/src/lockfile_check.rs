@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+use ring_traits::{Dependency, DependencyKind, Project, ResolvedPackage};
+use crate::DependencyGraph;
+
+/// Inconsistency between project manifests and their lockfile
+#[derive(Debug, Eq, PartialEq)]
+pub enum LockfileIssue<'a> {
+    /// Declared dependency without any entry in lockfile
+    Missing { project: &'a str, dependency: Dependency },
+    /// Declared dependency whose locked versions do not satisfy its requirement
+    OutOfRange { project: &'a str, dependency: Dependency, locked: Vec<&'a str> },
+    /// Locked package not required by any project
+    Extraneous { package: &'a ResolvedPackage },
+}
+
+/// Compares dependencies declared by given projects with packages resolved in lockfile.
+///
+/// Dependencies between given projects are ignored, as well as peer dependencies which are
+/// provided by dependents.
+pub fn check_lockfile<'a>(projects: &'a [Rc<dyn Project>], graph: &'a DependencyGraph) -> Vec<LockfileIssue<'a>> {
+    let locals: HashSet<&str> = projects.iter()
+        .map(|project| project.name())
+        .collect();
+
+    let mut issues = Vec::new();
+    let mut roots = Vec::new();
+
+    // Some lockfiles (like Cargo.lock) also list local projects
+    for local in &locals {
+        roots.extend(graph.packages_named(local));
+    }
+
+    for project in projects {
+        let dependencies = project.dependencies().into_iter()
+            .filter(|dependency| !locals.contains(dependency.name()));
+
+        for dependency in dependencies {
+            let resolved = graph.resolve(&dependency);
+
+            if !resolved.is_empty() {
+                roots.extend(resolved);
+                continue;
+            }
+
+            if dependency.kind() == DependencyKind::Peer {
+                continue;
+            }
+
+            let locked = graph.packages_named(dependency.name()).collect::<Vec<_>>();
+
+            if locked.is_empty() {
+                issues.push(LockfileIssue::Missing { project: project.name(), dependency });
+            } else {
+                issues.push(LockfileIssue::OutOfRange {
+                    project: project.name(),
+                    dependency,
+                    locked: locked.iter().map(|package| package.version.as_str()).collect(),
+                });
+
+                // Already reported as out of range, no need to report them as extraneous too
+                roots.extend(locked);
+            }
+        }
+    }
+
+    issues.extend(graph.unreachable(roots).into_iter()
+        .map(|package| LockfileIssue::Extraneous { package }));
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semver::VersionReq;
+    use ring_traits::PackageRef;
+    use crate::test_utils::TestProject;
+
+    fn project(name: &'static str, dependencies: &[(&str, &str, DependencyKind)]) -> Rc<dyn Project> {
+        let dependencies = dependencies.iter()
+            .map(|&(name, req, kind)| Dependency::new(name.to_string(), req.to_string(), kind)
+                .with_version_reqs(vec![VersionReq::parse(req).unwrap()]))
+            .collect();
+
+        TestProject::new(name, &format!("/repo/{name}"))
+            .with_dependencies(dependencies)
+            .into_project()
+    }
+
+    fn package(name: &str, version: &str, dependencies: &[&str]) -> ResolvedPackage {
+        ResolvedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            dependencies: dependencies.iter()
+                .map(|name| PackageRef::new(name.to_string(), None))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_should_accept_consistent_lockfile() {
+        let projects = vec![
+            project("app", &[("react", "^18.0.0", DependencyKind::Runtime), ("ui", "*", DependencyKind::Runtime)]),
+            project("ui", &[("react", "^18.0.0", DependencyKind::Peer)]),
+        ];
+        let graph = DependencyGraph::new(vec![
+            package("react", "18.3.1", &["loose-envify"]),
+            package("loose-envify", "1.4.0", &[]),
+        ]);
+
+        assert_eq!(check_lockfile(&projects, &graph), vec![]);
+    }
+
+    #[test]
+    fn it_should_report_missing_and_out_of_range_dependencies() {
+        let projects = vec![
+            project("app", &[
+                ("react", "^18.0.0", DependencyKind::Runtime),
+                ("typescript", "^5.0.0", DependencyKind::Dev),
+                ("vue", "^3.0.0", DependencyKind::Peer),
+            ]),
+        ];
+        let graph = DependencyGraph::new(vec![
+            package("react", "17.0.2", &[]),
+        ]);
+
+        let issues = check_lockfile(&projects, &graph);
+
+        assert_eq!(issues.len(), 2);
+        assert!(matches!(&issues[0], LockfileIssue::OutOfRange { project: "app", dependency, locked } if dependency.name() == "react" && locked == &["17.0.2"]));
+        assert!(matches!(&issues[1], LockfileIssue::Missing { project: "app", dependency } if dependency.name() == "typescript"));
+    }
+
+    #[test]
+    fn it_should_report_extraneous_packages() {
+        let projects = vec![
+            project("app", &[("react", "^18.0.0", DependencyKind::Runtime)]),
+        ];
+        let graph = DependencyGraph::new(vec![
+            package("app", "1.0.0", &["react", "lodash"]),
+            package("react", "18.3.1", &[]),
+            package("lodash", "4.17.21", &[]),
+            package("left-pad", "1.3.0", &[]),
+        ]);
+
+        let issues = check_lockfile(&projects, &graph);
+
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], LockfileIssue::Extraneous { package } if package.name == "left-pad"));
+    }
+
+    #[test]
+    #[cfg(feature = "rust")]
+    fn it_should_check_inherited_workspace_dependencies() {
+        use std::path::Path;
+        use ring_files::MemoryFileSystem;
+        use ring_utils::Normalize;
+        use crate::{Config, RingCore};
+
+        let root = Path::new("/repo").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\nserde = \"1.0.203\"\n");
+        fs.add_file(&root.join("crates/core/Cargo.toml"), "[package]\nname = \"core\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = { workspace = true }\n");
+        fs.add_file(&root.join("Cargo.lock"), concat!(
+            "version = 3\n\n",
+            "[[package]]\nname = \"core\"\nversion = \"0.1.0\"\ndependencies = [\"serde\"]\n\n",
+            "[[package]]\nname = \"serde\"\nversion = \"1.0.190\"\n",
+        ));
+
+        let core = RingCore::with_file_system(Config::default(), Rc::new(fs)).unwrap();
+        let scope = core.scope_detector().detect_at(&root).next().unwrap().unwrap();
+        let projects = scope.projects().collect::<ring_utils::Result<Vec<_>>>().unwrap();
+        let graph = DependencyGraph::new(Result::<Option<_>, _>::from(scope.resolved_packages()).unwrap().unwrap());
+
+        let issues = check_lockfile(&projects, &graph);
+
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], LockfileIssue::OutOfRange { project: "core", dependency, locked } if dependency.name() == "serde" && locked == &["1.0.190"]));
+    }
+}
@@ -0,0 +1,167 @@
+use std::cmp::Reverse;
+use std::fmt::{Debug, Formatter};
+use ring_traits::Module;
+use ring_utils::Error;
+
+pub const DEFAULT_PRIORITY: i32 = 0;
+
+struct ModuleEntry {
+    module: Box<dyn Module>,
+    priority: i32,
+    enabled: bool,
+}
+
+/// Set of modules known by ring, each one with a priority and an enabled state
+#[derive(Default)]
+pub struct ModuleRegistry {
+    entries: Vec<ModuleEntry>,
+}
+
+impl ModuleRegistry {
+    pub fn new() -> ModuleRegistry {
+        Default::default()
+    }
+
+    /// Registers an enabled module, with default priority
+    pub fn register(&mut self, module: Box<dyn Module>) {
+        self.register_with_priority(module, DEFAULT_PRIORITY);
+    }
+
+    /// Registers an enabled module, with given priority.
+    ///
+    /// Modules with higher priority are used first. A module registered with the name of an
+    /// existing one replaces it.
+    pub fn register_with_priority(&mut self, module: Box<dyn Module>, priority: i32) {
+        self.entries.retain(|entry| entry.module.name() != module.name());
+        self.entries.push(ModuleEntry { module, priority, enabled: true });
+
+        // Stable sort keeps registration order between modules of same priority
+        self.entries.sort_by_key(|entry| Reverse(entry.priority));
+    }
+
+    /// Returns module with given name, enabled or not
+    pub fn get(&self, name: &str) -> Option<&dyn Module> {
+        self.entries.iter()
+            .find(|entry| entry.module.name() == name)
+            .map(|entry| entry.module.as_ref())
+    }
+
+    /// Returns `true` if a module with given name is registered and enabled
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.entries.iter()
+            .any(|entry| entry.enabled && entry.module.name() == name)
+    }
+
+    /// Enables or disables module with given name. Returns `false` if no such module is registered.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.entries.iter_mut().find(|entry| entry.module.name() == name) {
+            Some(entry) => {
+                entry.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Enables only modules with given names, and disables every other module
+    pub fn enable_only<S: AsRef<str>>(&mut self, names: &[S]) -> ring_utils::Result<()> {
+        for name in names {
+            if self.get(name.as_ref()).is_none() {
+                return Err(Error::config(format!("Unknown module {}", name.as_ref())));
+            }
+        }
+
+        for entry in &mut self.entries {
+            entry.enabled = names.iter().any(|name| name.as_ref() == entry.module.name());
+        }
+
+        Ok(())
+    }
+
+    /// Returns enabled modules, ordered by priority
+    pub fn modules(&self) -> Vec<&dyn Module> {
+        self.entries.iter()
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.module.as_ref())
+            .collect()
+    }
+
+    /// Returns every registered module, ordered by priority, with its enabled state
+    pub fn all_modules(&self) -> Vec<(&dyn Module, bool)> {
+        self.entries.iter()
+            .map(|entry| (entry.module.as_ref(), entry.enabled))
+            .collect()
+    }
+}
+
+impl Debug for ModuleRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|entry| (entry.module.name(), entry.priority, entry.enabled)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestModule(&'static str);
+
+    impl Module for TestModule {
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    fn names(modules: Vec<&dyn Module>) -> Vec<&str> {
+        modules.into_iter().map(|module| module.name()).collect()
+    }
+
+    #[test]
+    fn it_should_order_modules_by_priority() {
+        let mut registry = ModuleRegistry::new();
+        registry.register(Box::new(TestModule("js")));
+        registry.register(Box::new(TestModule("rust")));
+        registry.register_with_priority(Box::new(TestModule("python")), 10);
+        registry.register_with_priority(Box::new(TestModule("go")), -10);
+
+        assert_eq!(names(registry.modules()), ["python", "js", "rust", "go"]);
+    }
+
+    #[test]
+    fn it_should_replace_module_with_same_name() {
+        let mut registry = ModuleRegistry::new();
+        registry.register(Box::new(TestModule("js")));
+        registry.register(Box::new(TestModule("rust")));
+        registry.register(Box::new(TestModule("js")));
+
+        assert_eq!(names(registry.modules()), ["rust", "js"]);
+    }
+
+    #[test]
+    fn it_should_enable_only_given_modules() {
+        let mut registry = ModuleRegistry::new();
+        registry.register(Box::new(TestModule("js")));
+        registry.register(Box::new(TestModule("rust")));
+
+        registry.enable_only(&["rust"]).unwrap();
+
+        assert_eq!(names(registry.modules()), ["rust"]);
+        assert!(!registry.is_enabled("js"));
+        assert_eq!(registry.all_modules().len(), 2);
+
+        assert!(registry.set_enabled("js", true));
+        assert_eq!(names(registry.modules()), ["js", "rust"]);
+    }
+
+    #[test]
+    fn it_should_reject_unknown_modules() {
+        let mut registry = ModuleRegistry::new();
+        registry.register(Box::new(TestModule("js")));
+
+        assert!(registry.enable_only(&["js", "python"]).is_err());
+        assert!(registry.is_enabled("js"));
+        assert!(!registry.set_enabled("python", true));
+    }
+}
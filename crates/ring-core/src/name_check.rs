@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use serde::Deserialize;
+use ring_traits::Project;
+use ring_utils::NormalizedPath;
+
+/// Rule project directory names should follow, compared to project names
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DirectoryNameRule {
+    /// Directory names are not checked
+    #[default]
+    Any,
+    /// Directory name equals project name
+    Exact,
+    /// Directory name equals project name without its npm scope (`ui` for `@acme/ui`)
+    Unscoped,
+    /// Project name, without its npm scope, ends with directory name (`cli` for `ring-cli`)
+    Suffix,
+}
+
+impl DirectoryNameRule {
+    /// Returns `true` if given directory name follows the rule for given project name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_core::DirectoryNameRule;
+    ///
+    /// assert!(DirectoryNameRule::Unscoped.matches("@acme/ui", "ui"));
+    /// assert!(DirectoryNameRule::Suffix.matches("ring-cli", "cli"));
+    /// assert!(!DirectoryNameRule::Exact.matches("ring-cli", "cli"));
+    /// ```
+    pub fn matches(&self, name: &str, directory: &str) -> bool {
+        match self {
+            DirectoryNameRule::Any => true,
+            DirectoryNameRule::Exact => name == directory,
+            DirectoryNameRule::Unscoped => unscoped_name(name) == directory,
+            DirectoryNameRule::Suffix => ends_with_word(unscoped_name(name), directory),
+        }
+    }
+}
+
+impl Display for DirectoryNameRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DirectoryNameRule::Any => write!(f, "any"),
+            DirectoryNameRule::Exact => write!(f, "exact"),
+            DirectoryNameRule::Unscoped => write!(f, "unscoped"),
+            DirectoryNameRule::Suffix => write!(f, "suffix"),
+        }
+    }
+}
+
+/// Returns name without its npm scope (`ui` for `@acme/ui`)
+pub(crate) fn unscoped_name(name: &str) -> &str {
+    name.split_once('/')
+        .filter(|_| name.starts_with('@'))
+        .map_or(name, |(_, name)| name)
+}
+
+/// Returns `true` if name ends with given suffix, preceded by nothing or by a separator
+pub(crate) fn ends_with_word(name: &str, suffix: &str) -> bool {
+    name.strip_suffix(suffix)
+        .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with(['-', '_', '.']))
+}
+
+/// Naming problem found among projects
+#[derive(Debug, Eq, PartialEq)]
+pub enum NameIssue<'a> {
+    /// Many projects share the same name
+    Duplicate { name: &'a str, roots: Vec<&'a NormalizedPath> },
+    /// Project directory name does not follow the directory name rule
+    Directory { name: &'a str, root: &'a NormalizedPath },
+}
+
+/// Searches projects sharing a name, and projects whose directory does not follow given rule.
+///
+/// A project listed many times (like a scope root project, also member of its scope) is
+/// counted once.
+pub fn check_names(projects: &[Rc<dyn Project>], rule: DirectoryNameRule) -> Vec<NameIssue<'_>> {
+    let mut by_name = BTreeMap::<&str, Vec<&Rc<dyn Project>>>::new();
+
+    for project in projects {
+        let known = by_name.entry(project.name()).or_default();
+
+        if !known.iter().any(|other| other.root() == project.root() && other.manifest_path() == project.manifest_path()) {
+            known.push(project);
+        }
+    }
+
+    let mut issues = Vec::new();
+
+    for (name, projects) in &by_name {
+        if projects.len() > 1 {
+            issues.push(NameIssue::Duplicate {
+                name,
+                roots: projects.iter().map(|project| project.root()).collect(),
+            });
+        }
+
+        for project in projects {
+            let directory = project.root().file_name().and_then(|name| name.to_str());
+
+            if directory.is_some_and(|directory| !rule.matches(name, directory)) {
+                issues.push(NameIssue::Directory { name, root: project.root() });
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::TestProject;
+    use super::*;
+
+    fn project(name: &'static str, root: &str, manifest: &'static str) -> Rc<dyn Project> {
+        TestProject::new(name, root).with_manifest(manifest).into_project()
+    }
+
+    #[test]
+    fn it_should_report_projects_sharing_a_name() {
+        let root = project("root", "/repo", "package.json");
+        let projects = vec![
+            root.clone(),
+            project("ring-cli", "/repo/crates/ring-cli", "Cargo.toml"),
+            project("ring-cli", "/repo/npm/ring-cli", "package.json"),
+            root,
+        ];
+
+        let issues = check_names(&projects, DirectoryNameRule::Any);
+
+        assert_eq!(issues, vec![
+            NameIssue::Duplicate {
+                name: "ring-cli",
+                roots: vec![projects[1].root(), projects[2].root()],
+            },
+        ]);
+    }
+
+    #[test]
+    fn it_should_report_directories_not_following_rule() {
+        let projects = vec![
+            project("@acme/ui", "/repo/packages/ui", "package.json"),
+            project("@acme/api", "/repo/packages/server", "package.json"),
+        ];
+
+        let issues = check_names(&projects, DirectoryNameRule::Unscoped);
+
+        assert_eq!(issues, vec![
+            NameIssue::Directory { name: "@acme/api", root: projects[1].root() },
+        ]);
+    }
+
+    #[test]
+    fn it_should_match_suffix_on_word_boundaries() {
+        assert!(DirectoryNameRule::Suffix.matches("ring-cli", "ring-cli"));
+        assert!(DirectoryNameRule::Suffix.matches("@acme/ring_cli", "cli"));
+        assert!(!DirectoryNameRule::Suffix.matches("ring-mycli", "cli"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use ring_traits::Project;
+use ring_utils::NormalizedPath;
+use crate::name_check::{ends_with_word, unscoped_name};
+use crate::RingCore;
+
+/// How a project name matched the searched name, from the most to the least precise
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum NameMatch {
+    /// Names are equal
+    Exact,
+    /// Names are equal without npm scope (`ui` for `@acme/ui`)
+    Unscoped,
+    /// Project name ends with searched name (`cli` for `ring-cli`)
+    Suffix,
+    /// Names differ by a few characters
+    Fuzzy,
+}
+
+impl NameMatch {
+    /// Returns the distance between project name and searched name if they match, `0` for every
+    /// match but fuzzy ones.
+    fn distance(&self, project: &str, name: &str) -> Option<usize> {
+        match self {
+            NameMatch::Exact => (project == name).then_some(0),
+            NameMatch::Unscoped => (unscoped_name(project) == name).then_some(0),
+            NameMatch::Suffix => ends_with_word(unscoped_name(project), name).then_some(0),
+            NameMatch::Fuzzy => {
+                let name = unscoped_name(name);
+                let distance = edit_distance(unscoped_name(project), name);
+
+                // Any name is within reach of very short ones
+                (distance <= MAX_FUZZY_DISTANCE && distance < name.len()).then_some(distance)
+            }
+        }
+    }
+}
+
+impl Display for NameMatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NameMatch::Exact => write!(f, "exact"),
+            NameMatch::Unscoped => write!(f, "unscoped"),
+            NameMatch::Suffix => write!(f, "suffix"),
+            NameMatch::Fuzzy => write!(f, "fuzzy"),
+        }
+    }
+}
+
+/// Result of a project name resolution
+pub enum Resolution {
+    Found { project: Rc<dyn Project>, matched: NameMatch },
+    /// Many projects match as precisely
+    Ambiguous { candidates: Vec<Rc<dyn Project>>, matched: NameMatch },
+    NotFound,
+}
+
+/// Searches projects by name among a set of projects.
+///
+/// Matches are tried from the most to the least precise, the first kind of match found wins.
+/// Fuzzy matches keep only projects with the smallest edit distance.
+pub struct ProjectResolver {
+    projects: Vec<Rc<dyn Project>>,
+}
+
+/// Maximum edit distance of fuzzy matches
+const MAX_FUZZY_DISTANCE: usize = 2;
+
+impl ProjectResolver {
+    pub fn new(projects: Vec<Rc<dyn Project>>) -> ProjectResolver {
+        ProjectResolver { projects }
+    }
+
+    pub fn resolve(&self, name: &str) -> Resolution {
+        for matched in [NameMatch::Exact, NameMatch::Unscoped, NameMatch::Suffix, NameMatch::Fuzzy] {
+            let distances = self.projects.iter()
+                .filter_map(|project| Some((matched.distance(project.name(), name)?, project)))
+                .collect::<Vec<_>>();
+
+            let nearest = distances.iter().map(|(distance, _)| *distance).min();
+            let candidates = distances.into_iter()
+                .filter(|(distance, _)| Some(*distance) == nearest)
+                .map(|(_, project)| project.clone())
+                .collect();
+
+            if let Some(resolution) = Resolution::from_candidates(candidates, matched) {
+                return resolution;
+            }
+        }
+
+        Resolution::NotFound
+    }
+}
+
+impl Resolution {
+    fn from_candidates(mut candidates: Vec<Rc<dyn Project>>, matched: NameMatch) -> Option<Resolution> {
+        match candidates.len() {
+            0 => None,
+            1 => Some(Resolution::Found { project: candidates.remove(0), matched }),
+            _ => Some(Resolution::Ambiguous { candidates, matched }),
+        }
+    }
+}
+
+/// Levenshtein distance between given strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+impl RingCore {
+    /// Resolves a project name among projects listed from given path (see [`RingCore::projects_from`]).
+    ///
+    /// Projects failing to load are skipped, unless no project matches.
+    pub fn resolve_project(&self, path: &NormalizedPath, name: &str) -> ring_utils::Result<Resolution> {
+        let (projects, errors) = self.collect_projects_from(path).into_parts();
+
+        match ProjectResolver::new(projects).resolve(name) {
+            Resolution::NotFound => match errors.into_iter().next() {
+                Some(err) => Err(err),
+                None => Ok(Resolution::NotFound),
+            },
+            resolution => Ok(resolution),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::TestProject;
+    use super::*;
+
+    fn resolver(names: &[&'static str]) -> ProjectResolver {
+        ProjectResolver::new(names.iter()
+            .map(|&name| TestProject::new(name, &format!("/repo/{name}")).into_project())
+            .collect())
+    }
+
+    fn found(resolution: Resolution) -> Option<(String, NameMatch)> {
+        match resolution {
+            Resolution::Found { project, matched } => Some((project.name().to_string(), matched)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn it_should_prefer_most_precise_matches() {
+        let resolver = resolver(&["ring-cli", "ring-cli-formatters", "@acme/ui", "api"]);
+
+        assert_eq!(found(resolver.resolve("ring-cli")), Some(("ring-cli".to_string(), NameMatch::Exact)));
+        assert_eq!(found(resolver.resolve("ui")), Some(("@acme/ui".to_string(), NameMatch::Unscoped)));
+        assert_eq!(found(resolver.resolve("formatters")), Some(("ring-cli-formatters".to_string(), NameMatch::Suffix)));
+        assert_eq!(found(resolver.resolve("ring-clj")), Some(("ring-cli".to_string(), NameMatch::Fuzzy)));
+        assert!(matches!(resolver.resolve("server"), Resolution::NotFound));
+    }
+
+    #[test]
+    fn it_should_report_ambiguous_names() {
+        let resolver = resolver(&["@acme/api", "@other/api", "@acme/ui"]);
+
+        assert_eq!(found(resolver.resolve("@acme/api")), Some(("@acme/api".to_string(), NameMatch::Exact)));
+        assert!(matches!(
+            resolver.resolve("api"),
+            Resolution::Ambiguous { candidates, matched: NameMatch::Unscoped } if candidates.len() == 2
+        ));
+    }
+
+    #[test]
+    fn it_should_compute_edit_distance() {
+        assert_eq!(edit_distance("ring-cli", "ring-cli"), 0);
+        assert_eq!(edit_distance("ring-cli", "ring-clj"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,200 @@
+use ring_traits::{Project, Scope};
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use std::rc::Rc;
+
+/// Scopes of one or many modules sharing the same root directory, seen as one logical workspace
+pub struct Workspace {
+    root: NormalizedPathBuf,
+    scopes: Vec<(String, Rc<dyn Scope>)>,
+    parent: Option<usize>,
+}
+
+impl Workspace {
+    pub fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    /// Returns workspace scopes, with the name of the module which detected them
+    pub fn scopes(&self) -> &[(String, Rc<dyn Scope>)] {
+        &self.scopes
+    }
+
+    /// Returns an iterator over projects of every workspace scope
+    pub fn projects(&self) -> impl Iterator<Item = ring_utils::Result<Rc<dyn Project>>> + '_ {
+        self.scopes.iter().flat_map(|(_, scope)| scope.projects())
+    }
+}
+
+/// Hierarchy of workspaces, each one being the child of the nearest workspace containing it
+#[derive(Default)]
+pub struct ScopeTree {
+    workspaces: Vec<Workspace>,
+}
+
+impl ScopeTree {
+    pub fn new() -> ScopeTree {
+        ScopeTree::default()
+    }
+
+    /// Adds scope to the workspace at its root, creating it if needed.
+    /// Returns false if module already has a scope at this root.
+    pub fn insert(&mut self, module: &str, scope: Rc<dyn Scope>) -> bool {
+        if let Some(workspace) = self.workspaces.iter_mut().find(|ws| ws.root == scope.root()) {
+            if workspace.scopes.iter().any(|(name, _)| name == module) {
+                return false;
+            }
+
+            workspace.scopes.push((module.to_string(), scope));
+            return true;
+        }
+
+        let index = self.workspaces.len();
+        let root = scope.root().to_path_buf();
+        let parent = self.nearest_index(&root);
+
+        // Workspaces inside the new one now have it as parent
+        for workspace in &mut self.workspaces {
+            if workspace.parent == parent && workspace.root.as_path().starts_with(&root) {
+                workspace.parent = Some(index);
+            }
+        }
+
+        self.workspaces.push(Workspace {
+            root,
+            scopes: vec![(module.to_string(), scope)],
+            parent,
+        });
+
+        true
+    }
+
+    fn nearest_index(&self, path: &NormalizedPath) -> Option<usize> {
+        self.workspaces.iter().enumerate()
+            .filter(|(_, ws)| path.as_path().starts_with(&ws.root))
+            .max_by_key(|(_, ws)| ws.root.as_path().components().count())
+            .map(|(idx, _)| idx)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.workspaces.is_empty()
+    }
+
+    /// Returns workspace rooted at given path
+    pub fn get(&self, root: &NormalizedPath) -> Option<&Workspace> {
+        self.workspaces.iter().find(|ws| ws.root == root)
+    }
+
+    /// Returns the innermost workspace containing given path
+    pub fn nearest(&self, path: &NormalizedPath) -> Option<&Workspace> {
+        self.nearest_index(path).map(|idx| &self.workspaces[idx])
+    }
+
+    pub fn parent(&self, workspace: &Workspace) -> Option<&Workspace> {
+        workspace.parent.map(|idx| &self.workspaces[idx])
+    }
+
+    pub fn children<'a>(&'a self, workspace: &'a Workspace) -> impl Iterator<Item = &'a Workspace> + 'a {
+        let index = self.index_of(workspace);
+        self.workspaces.iter().filter(move |ws| ws.parent.is_some() && ws.parent == index)
+    }
+
+    /// Returns the count of workspaces containing given one
+    pub fn depth(&self, workspace: &Workspace) -> usize {
+        let mut depth = 0;
+        let mut current = workspace;
+
+        while let Some(parent) = self.parent(current) {
+            depth += 1;
+            current = parent;
+        }
+
+        depth
+    }
+
+    /// Returns every workspace, each parent being followed by its children
+    pub fn iter(&self) -> impl Iterator<Item = &Workspace> {
+        let mut ordered = Vec::with_capacity(self.workspaces.len());
+        let mut stack = self.workspaces.iter().filter(|ws| ws.parent.is_none()).rev().collect::<Vec<_>>();
+
+        while let Some(workspace) = stack.pop() {
+            ordered.push(workspace);
+            stack.extend(self.children(workspace).collect::<Vec<_>>().into_iter().rev());
+        }
+
+        ordered.into_iter()
+    }
+
+    fn index_of(&self, workspace: &Workspace) -> Option<usize> {
+        self.workspaces.iter().position(|ws| std::ptr::eq(ws, workspace))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use ring_traits::{ProjectIterator, Tagged};
+    use ring_utils::{Normalize, Tag};
+    use super::*;
+
+    struct TestScope(NormalizedPathBuf);
+
+    impl Tagged for TestScope {
+        fn tags(&self) -> Vec<Tag> {
+            Vec::new()
+        }
+    }
+
+    impl Scope for TestScope {
+        fn root(&self) -> &NormalizedPath {
+            &self.0
+        }
+
+        fn projects(&self) -> Box<ProjectIterator<'_>> {
+            Box::new(std::iter::empty())
+        }
+    }
+
+    fn scope(root: &str) -> Rc<dyn Scope> {
+        Rc::new(TestScope(Path::new(root).normalize()))
+    }
+
+    #[test]
+    fn it_should_merge_co_located_scopes() {
+        let mut tree = ScopeTree::new();
+
+        assert!(tree.insert("js", scope("/repo")));
+        assert!(tree.insert("rust", scope("/repo")));
+        assert!(!tree.insert("js", scope("/repo")));
+
+        let roots = tree.iter().map(|ws| ws.root().to_path_buf()).collect::<Vec<_>>();
+        assert_eq!(roots, [Path::new("/repo").normalize()]);
+
+        let modules = tree.get(&Path::new("/repo").normalize()).unwrap()
+            .scopes().iter().map(|(module, _)| module.as_str()).collect::<Vec<_>>();
+        assert_eq!(modules, ["js", "rust"]);
+    }
+
+    #[test]
+    fn it_should_nest_scopes_whatever_the_insertion_order() {
+        let mut tree = ScopeTree::new();
+        tree.insert("js", scope("/repo/crates/web/apps"));
+        tree.insert("js", scope("/repo/tools"));
+        tree.insert("rust", scope("/repo"));
+        tree.insert("js", scope("/repo/crates/web"));
+
+        let workspaces = tree.iter()
+            .map(|ws| (ws.root().display().to_string(), tree.depth(ws)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(workspaces, [
+            ("/repo".to_string(), 0),
+            ("/repo/tools".to_string(), 1),
+            ("/repo/crates/web".to_string(), 1),
+            ("/repo/crates/web/apps".to_string(), 2),
+        ]);
+
+        let web = tree.nearest(&Path::new("/repo/crates/web/src").normalize()).unwrap();
+        assert_eq!(web.root(), Path::new("/repo/crates/web"));
+        assert_eq!(tree.parent(web).map(|ws| ws.root()), Some(Path::new("/repo").normalize().as_ref()));
+    }
+}
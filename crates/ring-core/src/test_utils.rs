@@ -0,0 +1,67 @@
+use std::path::Path;
+use std::rc::Rc;
+use semver::Version;
+use ring_traits::{Dependency, Project, Tagged};
+use ring_utils::{Normalize, NormalizedPath, NormalizedPathBuf, Tag};
+
+/// Project fixture, shared by ring-core tests
+pub struct TestProject {
+    name: &'static str,
+    root: NormalizedPathBuf,
+    manifest: Option<&'static str>,
+    dependencies: Vec<Dependency>,
+}
+
+impl TestProject {
+    pub fn new(name: &'static str, root: &str) -> TestProject {
+        TestProject {
+            name,
+            root: Path::new(root).normalize(),
+            manifest: None,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Sets manifest file name, relative to project root
+    pub fn with_manifest(mut self, manifest: &'static str) -> TestProject {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    pub fn with_dependencies(mut self, dependencies: Vec<Dependency>) -> TestProject {
+        self.dependencies = dependencies;
+        self
+    }
+
+    pub fn into_project(self) -> Rc<dyn Project> {
+        Rc::new(self)
+    }
+}
+
+impl Tagged for TestProject {
+    fn tags(&self) -> Vec<Tag> {
+        Vec::new()
+    }
+}
+
+impl Project for TestProject {
+    fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn version(&self) -> Option<&Version> {
+        None
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        self.dependencies.clone()
+    }
+
+    fn manifest_path(&self) -> Option<NormalizedPathBuf> {
+        self.manifest.map(|manifest| self.root.join(manifest))
+    }
+}
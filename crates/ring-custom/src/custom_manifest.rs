@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use serde_json::Value;
+use ring_traits::Manifest;
+use ring_utils::{Error, ManifestError};
+
+/// Format of a custom marker file
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ManifestFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ManifestFormat {
+    /// Guesses format from file extension
+    pub fn from_path(path: &Path) -> Option<ManifestFormat> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ManifestFormat::Json),
+            "toml" => Some(ManifestFormat::Toml),
+            "yaml" | "yml" => Some(ManifestFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ManifestFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ManifestFormat::Json),
+            "toml" => Ok(ManifestFormat::Toml),
+            "yaml" => Ok(ManifestFormat::Yaml),
+            _ => Err(Error::config(format!("Unknown manifest format {s}, expected json, toml or yaml"))),
+        }
+    }
+}
+
+impl Display for ManifestFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManifestFormat::Json => write!(f, "json"),
+            ManifestFormat::Toml => write!(f, "toml"),
+            ManifestFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+/// Raw content of a custom marker file, parsed only when fields are extracted from it
+#[derive(Debug)]
+pub struct CustomManifest {
+    content: String,
+}
+
+impl CustomManifest {
+    /// Parses content using given format
+    pub fn parse(&self, format: ManifestFormat) -> ring_utils::Result<Value> {
+        let value = match format {
+            ManifestFormat::Json => serde_json::from_str(&self.content)
+                .map_err(|err| ManifestError::at(&err.to_string(), err.line(), err.column())),
+            ManifestFormat::Toml => toml::from_str(&self.content)
+                .map_err(|err| match err.span() {
+                    Some(span) => ManifestError::new(err.message()).with_offset(&self.content, span.start),
+                    None => ManifestError::new(err.message()),
+                }),
+            ManifestFormat::Yaml => serde_yaml::from_str(&self.content)
+                .map_err(|err| match err.location() {
+                    Some(location) => ManifestError::at(&err.to_string(), location.line(), location.column()),
+                    None => ManifestError::new(err.to_string()),
+                }),
+        }?;
+
+        Ok(value)
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl Manifest for CustomManifest {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        Ok(CustomManifest { content: content.to_string() })
+    }
+}
+
+/// Returns string at given dot separated path (like `package.name`) in value
+pub fn lookup<'a>(value: &'a Value, path: &str) -> ring_utils::Result<Option<&'a str>> {
+    let mut current = value;
+
+    for key in path.split('.') {
+        let next = match current {
+            Value::Object(map) => map.get(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|idx| items.get(idx)),
+            _ => None,
+        };
+
+        match next {
+            Some(next) => current = next,
+            None => return Ok(None),
+        }
+    }
+
+    match current {
+        Value::String(value) => Ok(Some(value)),
+        Value::Null => Ok(None),
+        _ => Err(ManifestError::new("invalid type, expected a string").with_key(path).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_guess_format_from_extension() {
+        assert_eq!(ManifestFormat::from_path(Path::new("Chart.yaml")), Some(ManifestFormat::Yaml));
+        assert_eq!(ManifestFormat::from_path(Path::new("deno.json")), Some(ManifestFormat::Json));
+        assert_eq!(ManifestFormat::from_path(Path::new("pyproject.toml")), Some(ManifestFormat::Toml));
+        assert_eq!(ManifestFormat::from_path(Path::new("Dockerfile")), None);
+        assert_eq!(ManifestFormat::from_path(Path::new("terraform/main.tf")), None);
+    }
+
+    #[test]
+    fn it_should_lookup_fields_in_every_format() {
+        let json = CustomManifest::from_str(r#"{ "name": "chart", "versions": ["1.0.0"] }"#).unwrap()
+            .parse(ManifestFormat::Json).unwrap();
+        let toml = CustomManifest::from_str("[project]\nname = \"app\"").unwrap()
+            .parse(ManifestFormat::Toml).unwrap();
+        let yaml = CustomManifest::from_str("name: chart\nversion: 1.2.3").unwrap()
+            .parse(ManifestFormat::Yaml).unwrap();
+
+        assert_eq!(lookup(&json, "name").unwrap(), Some("chart"));
+        assert_eq!(lookup(&json, "versions.0").unwrap(), Some("1.0.0"));
+        assert_eq!(lookup(&toml, "project.name").unwrap(), Some("app"));
+        assert_eq!(lookup(&yaml, "version").unwrap(), Some("1.2.3"));
+        assert_eq!(lookup(&yaml, "description").unwrap(), None);
+    }
+
+    #[test]
+    fn it_should_reject_non_string_fields() {
+        let value = CustomManifest::from_str("version: 2").unwrap()
+            .parse(ManifestFormat::Yaml).unwrap();
+
+        assert!(lookup(&value, "version").is_err());
+    }
+
+    #[test]
+    fn it_should_fail_on_invalid_content() {
+        let manifest = CustomManifest::from_str("{ name").unwrap();
+
+        assert!(manifest.parse(ManifestFormat::Json).is_err());
+    }
+}
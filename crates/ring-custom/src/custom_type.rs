@@ -0,0 +1,100 @@
+use std::path::Path;
+use owo_colors::DynColors;
+use serde::Deserialize;
+use ring_utils::{Error, Tag};
+use crate::ManifestFormat;
+
+/// Definition of a custom project type, as written in `ring.toml`
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CustomType {
+    /// File marking project roots, relative to the project root (like `Chart.yaml` or `terraform/main.tf`)
+    pub marker: String,
+    /// Format of marker file (json, toml or yaml), guessed from its extension if missing
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Dot separated path of project name in marker file (defaults to the project directory name)
+    #[serde(default, rename = "name")]
+    pub name_field: Option<String>,
+    /// Dot separated path of project version in marker file
+    #[serde(default, rename = "version")]
+    pub version_field: Option<String>,
+    /// Label of the project tag (defaults to the type name)
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Color name, like `blue`, or hex code, like `#ff8000`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Glob patterns of member projects, relative to the marker directory. Directories with a
+    /// marker file are scopes if some are given.
+    #[serde(default)]
+    pub members: Vec<String>,
+    /// Priority of the module detecting this type, against other modules
+    #[serde(default)]
+    pub priority: Option<i32>,
+}
+
+impl CustomType {
+    /// Returns format of marker file, required only if fields are read from it
+    pub(crate) fn manifest_format(&self) -> ring_utils::Result<Option<ManifestFormat>> {
+        if let Some(format) = &self.format {
+            return format.parse().map(Some);
+        }
+
+        if self.name_field.is_none() && self.version_field.is_none() {
+            return Ok(None);
+        }
+
+        match ManifestFormat::from_path(Path::new(&self.marker)) {
+            Some(format) => Ok(Some(format)),
+            None => Err(Error::config(format!("Unable to guess format of {}, it should be given", self.marker))),
+        }
+    }
+
+    /// Builds tag of this type
+    pub(crate) fn build_tag(&self, name: &str) -> ring_utils::Result<Tag> {
+        let color = self.color.as_ref()
+            .map(|color| color.parse::<DynColors>().map_err(|_| Error::config(format!("Invalid tag color {color}"))))
+            .transpose()?;
+
+        Ok(Tag::owned(self.tag.as_deref().unwrap_or(name).to_string(), color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_guess_format_only_when_fields_are_read() {
+        let dockerfile = CustomType { marker: "Dockerfile".to_string(), ..Default::default() };
+        let chart = CustomType {
+            marker: "Chart.yaml".to_string(),
+            name_field: Some("name".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(dockerfile.manifest_format().unwrap(), None);
+        assert_eq!(chart.manifest_format().unwrap(), Some(ManifestFormat::Yaml));
+    }
+
+    #[test]
+    fn it_should_require_format_of_unknown_extensions() {
+        let terraform = CustomType {
+            marker: "terraform/main.tf".to_string(),
+            version_field: Some("version".to_string()),
+            ..Default::default()
+        };
+
+        assert!(terraform.manifest_format().is_err());
+        assert!(CustomType { format: Some("xml".to_string()), ..terraform }.manifest_format().is_err());
+    }
+
+    #[test]
+    fn it_should_build_tag_with_type_name_by_default() {
+        let custom_type = CustomType { marker: "Dockerfile".to_string(), ..Default::default() };
+
+        assert_eq!(custom_type.build_tag("docker").unwrap().label(), "docker");
+        assert!(CustomType { color: Some("not-a-color".to_string()), ..custom_type }.build_tag("docker").is_err());
+    }
+}
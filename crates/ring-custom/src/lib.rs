@@ -0,0 +1,134 @@
+mod custom_manifest;
+mod custom_type;
+mod project;
+mod project_detector;
+mod scope;
+mod scope_detector;
+
+use std::rc::Rc;
+use tracing::debug;
+pub use custom_manifest::{CustomManifest, ManifestFormat};
+pub use custom_type::CustomType;
+pub use project::CustomProject;
+pub use project_detector::CustomProjectDetector;
+use ring_files::{FileSystem, RealFileSystem};
+use ring_traits::{Module, ProjectDetector, ScopeDetector, TaggedDetector};
+pub use scope::CustomScope;
+pub use scope_detector::CustomScopeDetector;
+
+// Module
+#[derive(Debug)]
+pub struct CustomModule {
+    name: String,
+    project_detector: Rc<CustomProjectDetector>,
+    scope_detector: Option<Rc<CustomScopeDetector>>,
+}
+
+impl CustomModule {
+    /// Builds a module detecting projects of given type
+    pub fn new(name: String, custom_type: CustomType) -> ring_utils::Result<CustomModule> {
+        CustomModule::with_fs(name, custom_type, Rc::new(RealFileSystem))
+    }
+
+    /// Builds a module detecting projects of given type in given file system
+    pub fn with_fs(name: String, custom_type: CustomType, fs: Rc<dyn FileSystem>) -> ring_utils::Result<CustomModule> {
+        debug!("Initializing {} custom module", name);
+
+        let project_detector = Rc::new(CustomProjectDetector::new(
+            custom_type.marker.clone(),
+            custom_type.manifest_format()?,
+            custom_type.name_field.clone(),
+            custom_type.version_field.clone(),
+            custom_type.build_tag(&name)?,
+            fs,
+        ));
+
+        let scope_detector = Some(custom_type.members)
+            .filter(|members| !members.is_empty())
+            .map(|members| Rc::new(CustomScopeDetector::new(members, project_detector.clone())));
+
+        Ok(CustomModule { name, project_detector, scope_detector })
+    }
+}
+
+impl Module for CustomModule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn project_detectors(&self) -> Vec<Rc<ProjectDetector>> {
+        vec![
+            self.project_detector.clone()
+        ]
+    }
+
+    fn scope_detectors(&self) -> Vec<Rc<ScopeDetector>> {
+        self.scope_detector.iter()
+            .map(|detector| detector.clone() as Rc<ScopeDetector>)
+            .collect()
+    }
+
+    fn tagged_detectors(&self) -> Vec<Rc<TaggedDetector>> {
+        let mut detectors: Vec<Rc<TaggedDetector>> = vec![self.project_detector.clone()];
+        detectors.extend(self.scope_detector.iter().map(|detector| detector.clone() as Rc<TaggedDetector>));
+
+        detectors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use ring_files::MemoryFileSystem;
+    use ring_traits::{Detect, Project, Scope, Tagged};
+    use ring_utils::Normalize;
+    use ring_utils::OptionalResult::Found;
+    use super::*;
+
+    #[test]
+    fn it_should_detect_projects_and_scopes_of_custom_type() {
+        let dir = Path::new("/platform").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&dir.join("Chart.yaml"), "name: platform\nversion: 1.0.0");
+        fs.add_file(&dir.join("charts/api/Chart.yaml"), "name: api\nversion: 0.2.0");
+        fs.add_file(&dir.join("charts/web/Chart.yaml"), "description: no name");
+
+        let module = CustomModule::with_fs("helm".to_string(), CustomType {
+            marker: "Chart.yaml".to_string(),
+            name_field: Some("name".to_string()),
+            version_field: Some("version".to_string()),
+            members: vec!["charts/*".to_string()],
+            ..Default::default()
+        }, Rc::new(fs)).unwrap();
+
+        let Found(scope) = module.scope_detector.as_ref().unwrap().detect_at(&dir) else { panic!("Scope not found") };
+        let mut projects = scope.projects()
+            .map(|prj| prj.map(|prj| (prj.name().to_string(), prj.version().map(|v| v.to_string()))))
+            .collect::<ring_utils::Result<Vec<_>>>();
+        let Found(root) = module.project_detector.detect_from(&dir.join("charts")) else { panic!("Project not found") };
+
+        projects.as_mut().unwrap().sort();
+        assert_eq!(projects.unwrap(), [
+            ("api".to_string(), Some("0.2.0".to_string())),
+            ("web".to_string(), None),
+        ]);
+        assert_eq!(root.name(), "platform");
+        assert_eq!(root.tags()[0].label(), "helm");
+    }
+
+    #[test]
+    fn it_should_detect_projects_from_nested_markers() {
+        let dir = Path::new("/infra").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&dir.join("terraform/main.tf"), "");
+
+        let module = CustomModule::with_fs("terraform".to_string(), CustomType {
+            marker: "terraform/main.tf".to_string(),
+            ..Default::default()
+        }, Rc::new(fs)).unwrap();
+
+        let Found(project) = module.project_detector.detect_at(&dir.join("terraform/main.tf")) else { panic!("Project not found") };
+        assert_eq!(project.root(), dir.as_path());
+        assert_eq!(project.name(), "infra");
+    }
+}
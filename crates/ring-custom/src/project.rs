@@ -0,0 +1,47 @@
+use semver::Version;
+use ring_traits::{Dependency, Project, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
+
+/// Project of a custom type
+#[derive(Debug)]
+pub struct CustomProject {
+    root: NormalizedPathBuf,
+    name: String,
+    version: Option<Version>,
+    manifest_path: NormalizedPathBuf,
+    tag: Tag,
+}
+
+impl CustomProject {
+    pub fn new(root: NormalizedPathBuf, name: String, version: Option<Version>, manifest_path: NormalizedPathBuf, tag: Tag) -> CustomProject {
+        CustomProject { root, name, version, manifest_path, tag }
+    }
+}
+
+impl Project for CustomProject {
+    fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        Vec::new()
+    }
+
+    fn manifest_path(&self) -> Option<NormalizedPathBuf> {
+        Some(self.manifest_path.clone())
+    }
+}
+
+impl Tagged for CustomProject {
+    fn tags(&self) -> Vec<Tag> {
+        vec![self.tag.clone()]
+    }
+}
@@ -0,0 +1,117 @@
+use crate::custom_manifest::lookup;
+use crate::{CustomManifest, CustomProject, ManifestFormat};
+use ring_files::{FileSystem, ManifestLoader};
+use ring_traits::{Detect, DetectAs, Project, Tagged, detect_as, detect_from};
+use ring_utils::OptionalResult::{self, Found};
+use ring_utils::{Error, ManifestError, NormalizedPath, PathTree, Tag};
+use semver::Version;
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+use tracing::{debug, info};
+
+#[derive(Debug)]
+pub struct CustomProjectDetector {
+    cache: RefCell<PathTree<Rc<CustomProject>>>,
+    marker_loader: ManifestLoader<CustomManifest>,
+    format: Option<ManifestFormat>,
+    name_field: Option<String>,
+    version_field: Option<String>,
+    marker: String,
+    tag: Tag,
+    fs: Rc<dyn FileSystem>,
+}
+
+impl CustomProjectDetector {
+    pub(crate) fn new(marker: String, format: Option<ManifestFormat>, name_field: Option<String>, version_field: Option<String>, tag: Tag, fs: Rc<dyn FileSystem>) -> CustomProjectDetector {
+        CustomProjectDetector {
+            cache: RefCell::new(PathTree::new()),
+            marker_loader: ManifestLoader::new(marker.clone(), fs.clone()),
+            marker,
+            format,
+            name_field,
+            version_field,
+            tag,
+            fs,
+        }
+    }
+
+    pub(crate) fn marker_loader(&self) -> &ManifestLoader<CustomManifest> {
+        &self.marker_loader
+    }
+
+    pub(crate) fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    pub(crate) fn fs(&self) -> &Rc<dyn FileSystem> {
+        &self.fs
+    }
+
+    /// Returns root of project owning given file. Nested markers, like `terraform/main.tf`, are
+    /// stripped entirely.
+    fn root_of<'a>(&self, file: &'a NormalizedPath) -> &'a NormalizedPath {
+        let depth = if file.as_path().ends_with(&self.marker) { Path::new(&self.marker).components().count() } else { 1 };
+
+        file.ancestors().nth(depth).unwrap_or(file)
+    }
+
+    fn build_project(&self, path: &NormalizedPath, manifest: &CustomManifest) -> ring_utils::Result<CustomProject> {
+        let mut name = None;
+        let mut version = None;
+
+        if let Some(format) = self.format {
+            let value = manifest.parse(format)?;
+
+            if let Some(field) = &self.name_field {
+                name = lookup(&value, field)?.map(str::to_string);
+            }
+
+            if let Some(field) = &self.version_field {
+                version = lookup(&value, field)?
+                    .map(|version| Version::parse(version)
+                        .map_err(|err| Error::from(ManifestError::new(format!("invalid version {version}: {err}")).with_key(field.clone()))))
+                    .transpose()?;
+            }
+        }
+
+        let name = name
+            .or_else(|| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .unwrap_or_default();
+
+        Ok(CustomProject::new(path.to_path_buf(), name, version, path.join(&self.marker), self.tag.clone()))
+    }
+}
+
+impl Detect for CustomProjectDetector {
+    type Item = Rc<CustomProject>;
+
+    fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
+        let path = if self.fs.is_file(path) { self.root_of(path) } else { path };
+
+        if let Some(project) = self.cache.borrow().get(path) {
+            debug!("Found {} project {} at {} (cached)", self.tag.label(), project.name(), path.display());
+            return Found(project.clone());
+        }
+
+        self.marker_loader.load(path)
+            .and_then(|mnf| self.build_project(path, &mnf)
+                .map_err(|err| match err {
+                    Error::Manifest(err) => err.with_path(path.join(&self.marker)).with_content(mnf.content()).into(),
+                    err => err,
+                }))
+            .map(Rc::new)
+            .inspect(|prj| {
+                debug!("Found {} project {} at {}", self.tag.label(), prj.name(), path.display());
+                self.cache.borrow_mut().set(path, prj.clone());
+            })
+    }
+
+    fn detect_from(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
+        info!("Searching {} project from {}", self.tag.label(), path.display());
+        detect_from!(self, path)
+    }
+}
+
+detect_as!(CustomProjectDetector, Rc<dyn Project>);
+detect_as!(CustomProjectDetector, Rc<dyn Tagged>);
@@ -0,0 +1,48 @@
+use std::rc::Rc;
+use tracing::debug;
+use ring_files::PatternIterator;
+use ring_traits::{Detect, Project, ProjectIterator, Scope, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, OptionalResult, Tag};
+use crate::CustomProjectDetector;
+
+/// Scope of a custom type, its projects are found using member patterns
+#[derive(Debug)]
+pub struct CustomScope {
+    root: NormalizedPathBuf,
+    members: Rc<Vec<String>>,
+    tag: Tag,
+    project_detector: Rc<CustomProjectDetector>,
+}
+
+impl CustomScope {
+    pub fn new(root: NormalizedPathBuf, members: Rc<Vec<String>>, tag: Tag, project_detector: Rc<CustomProjectDetector>) -> CustomScope {
+        CustomScope { root, members, tag, project_detector }
+    }
+}
+
+impl Scope for CustomScope {
+    fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    fn root_project(&self) -> OptionalResult<Rc<dyn Project>> {
+        self.project_detector.detect_at(&self.root)
+            .map(|prj| prj as Rc<dyn Project>)
+    }
+
+    fn projects(&self) -> Box<ProjectIterator<'_>> {
+        let projects = self.members.iter()
+            .resolve(self.root())
+            .inspect(|pattern| debug!("Search {} project matching {}", self.tag.label(), pattern.display()))
+            .glob_search(self.project_detector.fs().clone())
+            .try_detect_at(self.project_detector.clone());
+
+        Box::new(projects)
+    }
+}
+
+impl Tagged for CustomScope {
+    fn tags(&self) -> Vec<Tag> {
+        vec![self.tag.clone()]
+    }
+}
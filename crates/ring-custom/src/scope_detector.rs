@@ -0,0 +1,58 @@
+use crate::{CustomManifest, CustomProjectDetector, CustomScope};
+use ring_files::ManifestLoader;
+use ring_traits::{Detect, DetectAs, Scope, Tagged, detect_as, detect_from};
+use ring_utils::OptionalResult::{self, Found};
+use ring_utils::{NormalizedPath, PathTree};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tracing::{debug, info};
+
+#[derive(Debug)]
+pub struct CustomScopeDetector {
+    cache: RefCell<PathTree<Rc<CustomScope>>>,
+    members: Rc<Vec<String>>,
+    project_detector: Rc<CustomProjectDetector>,
+}
+
+impl CustomScopeDetector {
+    pub fn new(members: Vec<String>, project_detector: Rc<CustomProjectDetector>) -> CustomScopeDetector {
+        CustomScopeDetector {
+            cache: RefCell::new(PathTree::new()),
+            members: Rc::new(members),
+            project_detector,
+        }
+    }
+
+    fn marker_loader(&self) -> &ManifestLoader<CustomManifest> {
+        self.project_detector.marker_loader()
+    }
+}
+
+impl Detect for CustomScopeDetector {
+    type Item = Rc<CustomScope>;
+
+    fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
+        let path = if self.project_detector.fs().is_file(path) { path.parent().unwrap() } else { path };
+        let tag = self.project_detector.tag();
+
+        if let Some(scope) = self.cache.borrow().get(path) {
+            debug!("Found {} scope at {} (cached)", tag.label(), path.display());
+            return Found(scope.clone());
+        }
+
+        self.marker_loader().load(path)
+            .map(|_| Rc::new(CustomScope::new(path.to_path_buf(), self.members.clone(), tag.clone(), self.project_detector.clone())))
+            .inspect(|scp| {
+                debug!("Found {} scope at {}", tag.label(), path.display());
+                self.cache.borrow_mut().set(path, scp.clone());
+            })
+    }
+
+    fn detect_from(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
+        info!("Searching {} scope from {}", self.project_detector.tag().label(), path.display());
+        detect_from!(self, path)
+    }
+}
+
+detect_as!(CustomScopeDetector, Rc<dyn Scope>);
+detect_as!(CustomScopeDetector, Rc<dyn Tagged>);
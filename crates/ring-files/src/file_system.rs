@@ -0,0 +1,64 @@
+use ring_utils::{Normalize, NormalizedPath, NormalizedPathBuf};
+use std::fmt::Debug;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+
+/// Kind of entry found in a file system
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FileKind {
+    File,
+    Dir,
+}
+
+/// Read-only access to a file system, used by detectors to inspect projects
+pub trait FileSystem: Debug {
+    /// Reads whole content of given file
+    fn read_to_string(&self, path: &NormalizedPath) -> io::Result<String>;
+
+    /// Returns kind of given entry, or `None` if it does not exist
+    fn kind(&self, path: &NormalizedPath) -> io::Result<Option<FileKind>>;
+
+    /// Returns paths of entries in given directory, sorted by name
+    fn read_dir(&self, path: &NormalizedPath) -> io::Result<Vec<NormalizedPathBuf>>;
+
+    fn try_exists(&self, path: &NormalizedPath) -> io::Result<bool> {
+        self.kind(path).map(|kind| kind.is_some())
+    }
+
+    fn is_file(&self, path: &NormalizedPath) -> bool {
+        matches!(self.kind(path), Ok(Some(FileKind::File)))
+    }
+
+    fn is_dir(&self, path: &NormalizedPath) -> bool {
+        matches!(self.kind(path), Ok(Some(FileKind::Dir)))
+    }
+}
+
+/// File system of the current machine, backed by [`std::fs`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    fn read_to_string(&self, path: &NormalizedPath) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn kind(&self, path: &NormalizedPath) -> io::Result<Option<FileKind>> {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.is_dir() => Ok(Some(FileKind::Dir)),
+            Ok(_) => Ok(Some(FileKind::File)),
+            Err(err) if matches!(err.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn read_dir(&self, path: &NormalizedPath) -> io::Result<Vec<NormalizedPathBuf>> {
+        let mut paths = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path().normalize()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        paths.sort();
+        Ok(paths)
+    }
+}
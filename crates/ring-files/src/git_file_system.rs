@@ -0,0 +1,219 @@
+use crate::memory_file_system::{not_found, PathIndex};
+use crate::{FileKind, FileSystem};
+use ring_utils::{Context, Error, Normalize, NormalizedPath, NormalizedPathBuf};
+use std::cell::RefCell;
+use std::io;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use tracing::debug;
+
+/// File system reading files of a git repository as they were at a given revision.
+///
+/// Tree of the revision is listed once on creation, file contents are read on demand by a single
+/// `git cat-file --batch` process.
+#[derive(Debug)]
+pub struct GitFileSystem {
+    root: NormalizedPathBuf,
+    commit: String,
+    index: PathIndex,
+    cat_file: RefCell<Option<CatFile>>,
+}
+
+impl GitFileSystem {
+    /// Opens repository containing given path, at given revision (anything accepted by
+    /// `git rev-parse`: commit, branch, tag, ...)
+    pub fn new(path: &NormalizedPath, revision: &str) -> ring_utils::Result<GitFileSystem> {
+        // --show-toplevel returns a canonical path, which may not share its prefix with given
+        // path (through a symlinked /var for example). Root is resolved from given path instead.
+        let root = Path::new(git(path, &["rev-parse", "--show-cdup"])?.trim_end()).resolve(path);
+        let commit = git(&root, &["rev-parse", "--verify", &format!("{revision}^{{commit}}")])
+            .with_context(|| format!("Unknown revision {revision}"))?
+            .trim_end()
+            .to_string();
+
+        debug!("Listing files of {} at {}", root.display(), commit);
+        let mut index = PathIndex::default();
+
+        for file in git(&root, &["ls-tree", "-r", "-z", "--name-only", &commit])?.split('\0') {
+            if !file.is_empty() {
+                index.insert_file(&root.join(file));
+            }
+        }
+
+        Ok(GitFileSystem { root, commit, index, cat_file: RefCell::new(None) })
+    }
+
+    /// Root of the git repository
+    pub fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    /// Hash of the commit read by this file system
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+}
+
+impl FileSystem for GitFileSystem {
+    fn read_to_string(&self, path: &NormalizedPath) -> io::Result<String> {
+        if self.index.kind(path) != Some(FileKind::File) {
+            return Err(not_found(path));
+        }
+
+        let relative = path.as_path().strip_prefix(&self.root)
+            .map_err(|_| not_found(path))?;
+        let object = format!("{}:{}", self.commit, relative.to_string_lossy().replace('\\', "/"));
+
+        if object.contains('\n') {
+            return Err(io::Error::new(ErrorKind::InvalidInput, format!("{} cannot be read from git", path.display())));
+        }
+
+        let mut cat_file = self.cat_file.borrow_mut();
+
+        if cat_file.is_none() {
+            *cat_file = Some(CatFile::spawn(&self.root)?);
+        }
+
+        let content = cat_file.as_mut().expect("git cat-file should be running").read(&object);
+
+        // Output may be left half read, next read will need a new process
+        if content.is_err() {
+            *cat_file = None;
+        }
+
+        String::from_utf8(content?.ok_or_else(|| not_found(path))?)
+            .map_err(|_| io::Error::new(ErrorKind::InvalidData, format!("{} is not valid utf-8", path.display())))
+    }
+
+    fn kind(&self, path: &NormalizedPath) -> io::Result<Option<FileKind>> {
+        Ok(self.index.kind(path))
+    }
+
+    fn read_dir(&self, path: &NormalizedPath) -> io::Result<Vec<NormalizedPathBuf>> {
+        self.index.read_dir(path)
+    }
+}
+
+/// Long-lived `git cat-file --batch` process, reading objects one after another
+#[derive(Debug)]
+struct CatFile {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl CatFile {
+    fn spawn(dir: &NormalizedPath) -> io::Result<CatFile> {
+        let mut child = Command::new("git")
+            .args(["cat-file", "--batch"])
+            .current_dir(dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let stdin = child.stdin.take().expect("git cat-file stdin should be piped");
+        let stdout = BufReader::new(child.stdout.take().expect("git cat-file stdout should be piped"));
+
+        Ok(CatFile { child, stdin, stdout })
+    }
+
+    /// Returns content of given object, or `None` if it does not exist
+    fn read(&mut self, object: &str) -> io::Result<Option<Vec<u8>>> {
+        writeln!(self.stdin, "{object}")?;
+        self.stdin.flush()?;
+
+        let mut header = String::new();
+
+        if self.stdout.read_line(&mut header)? == 0 {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "git cat-file exited"));
+        }
+
+        // Header is either "<oid> <type> <size>" or "<object> missing"
+        let Some(size) = header.trim_end().rsplit(' ').next().and_then(|size| size.parse::<usize>().ok()) else {
+            return Ok(None);
+        };
+
+        // Content is followed by a line feed
+        let mut content = vec![0; size + 1];
+        self.stdout.read_exact(&mut content)?;
+        content.pop();
+
+        Ok(Some(content))
+    }
+}
+
+impl Drop for CatFile {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Runs a git command in given directory, and returns its standard output
+fn git(dir: &NormalizedPath, args: &[&str]) -> ring_utils::Result<String> {
+    let program = format!("git {}", args.join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|err| Error::command(&program, err))?;
+
+    if !output.status.success() {
+        return Err(Error::command(&program, String::from_utf8_lossy(&output.stderr).trim_end()));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|_| Error::command(&program, "returned invalid utf-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn it_should_read_files_at_revision() {
+        let dir = env::temp_dir().join(format!("ring-files-git-{}", std::process::id())).normalize();
+        fs::create_dir_all(dir.join("packages/api")).unwrap();
+        fs::write(dir.join("packages/api/package.json"), r#"{ "name": "api" }"#).unwrap();
+
+        let commit = |message: &str| {
+            for args in [vec!["add", "-A"], vec!["-c", "user.name=test", "-c", "user.email=test@example.com", "commit", "-q", "-m", message]] {
+                git(&dir, &args).unwrap();
+            }
+        };
+
+        git(&dir, &["init", "-q"]).unwrap();
+        commit("first");
+        fs::write(dir.join("packages/api/package.json"), r#"{ "name": "renamed" }"#).unwrap();
+        fs::write(dir.join("package.json"), "{}").unwrap();
+        commit("second");
+
+        let git_fs = GitFileSystem::new(&dir.join("packages"), "HEAD~1").unwrap();
+        let content = git_fs.read_to_string(&dir.join("packages/api/package.json"));
+        let invalid = GitFileSystem::new(&dir, "does-not-exist");
+
+        // Paths given through a symlink keep their prefix
+        let link = env::temp_dir().join(format!("ring-files-git-link-{}", std::process::id())).normalize();
+        std::os::unix::fs::symlink(&dir, &link).unwrap();
+        let linked_fs = GitFileSystem::new(&link, "HEAD").unwrap();
+        let contents = [
+            linked_fs.read_to_string(&link.join("packages/api/package.json")).ok(),
+            linked_fs.read_to_string(&link.join("package.json")).ok(),
+            linked_fs.read_to_string(&link.join("packages/api/package.json")).ok(),
+        ];
+
+        fs::remove_file(&link).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(content.unwrap(), r#"{ "name": "api" }"#);
+        assert!(!git_fs.try_exists(&dir.join("package.json")).unwrap());
+        assert!(git_fs.is_dir(&dir.join("packages")));
+        assert!(invalid.is_err());
+        assert_eq!(linked_fs.root().to_path_buf(), link);
+        assert_eq!(contents.map(|content| content.unwrap()), [r#"{ "name": "renamed" }"#, "{}", r#"{ "name": "renamed" }"#]);
+    }
+}
@@ -0,0 +1,535 @@
+use crate::{FileSystem, IgnoreMatcher};
+use ring_utils::{Error, Normalize, NormalizedPath, NormalizedPathBuf};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+/// Glob pattern, following the dialect of npm, yarn and pnpm workspaces:
+/// - `*`, `?` and `[...]` classes match inside a path segment,
+/// - `**` matches any number of segments,
+/// - `{a,b}` and `{1..3}` braces are expanded,
+/// - `@(a|b)`, `?(a|b)`, `*(a|b)`, `+(a|b)` and `!(a|b)` extglobs,
+/// - a leading `!` negates the whole pattern.
+///
+/// Wildcards never match names starting with a dot, unless the pattern segment itself starts with a dot.
+#[derive(Debug, Clone)]
+pub struct GlobPattern {
+    pattern: String,
+    negated: bool,
+    alternatives: Vec<Vec<Segment>>,
+}
+
+impl GlobPattern {
+    pub fn new(source: &str) -> GlobPattern {
+        let (negated, pattern) = match source.strip_prefix('!') {
+            Some(pattern) if !pattern.starts_with('(') => (true, pattern),
+            _ => (false, source),
+        };
+
+        let alternatives = expand_braces(pattern).iter()
+            .map(|pattern| Path::new(pattern).components()
+                .filter(|component| component != &Component::CurDir)
+                .map(|component| match component {
+                    Component::Normal(name) => Segment::parse(&name.to_string_lossy()),
+                    other => Segment::Literal(other.as_os_str().to_string_lossy().into_owned()),
+                })
+                .collect())
+            .collect();
+
+        GlobPattern { pattern: source.to_string(), negated, alternatives }
+    }
+
+    /// Returns `true` if pattern starts with `!`
+    pub fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Returns `true` if given path matches pattern, without accounting for negation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use ring_files::GlobPattern;
+    ///
+    /// let pattern = GlobPattern::new("packages/{core,cli}/**");
+    ///
+    /// assert!(pattern.matches(Path::new("packages/core/src")));
+    /// assert!(!pattern.matches(Path::new("packages/web")));
+    /// ```
+    pub fn matches(&self, path: &Path) -> bool {
+        let names = path.components()
+            .filter(|component| component != &Component::CurDir)
+            .map(|component| component.as_os_str().to_string_lossy().chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        self.alternatives.iter()
+            .any(|segments| match_segments(segments, &names))
+    }
+
+    /// Searches paths matching pattern in given file system, skipping paths excluded by given
+    /// matcher below the literal base of the pattern. Relative patterns are resolved from given
+    /// base directory.
+    /// Results are sorted and deduplicated.
+    pub fn search(&self, fs: &dyn FileSystem, base_dir: &NormalizedPath, ignore: &IgnoreMatcher) -> ring_utils::Result<Vec<NormalizedPathBuf>> {
+        let mut results = BTreeSet::new();
+
+        for segments in &self.alternatives {
+            let literals = segments.iter()
+                .take_while(|segment| matches!(segment, Segment::Literal(_)))
+                .count();
+
+            let base = segments[..literals].iter()
+                .map(|segment| match segment {
+                    Segment::Literal(name) => name.as_str(),
+                    _ => unreachable!(),
+                })
+                .collect::<PathBuf>();
+            let base = base.resolve(base_dir);
+
+            if fs.try_exists(&base).map_err(|err| Error::io(&base, err))? {
+                search_in(fs, &base, &base, &segments[literals..], ignore, &mut results)?;
+            }
+        }
+
+        Ok(results.into_iter().collect())
+    }
+}
+
+impl Display for GlobPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Globstar,
+    Pattern(Vec<Token>),
+}
+
+impl Segment {
+    fn parse(segment: &str) -> Segment {
+        if segment == "**" {
+            return Segment::Globstar;
+        }
+
+        let chars = segment.chars().collect::<Vec<_>>();
+        let mut pos = 0;
+        let tokens = parse_tokens(&chars, &mut pos, false);
+
+        if tokens.iter().all(|token| matches!(token, Token::Literal(_))) {
+            Segment::Literal(tokens.iter()
+                .map(|token| match token {
+                    Token::Literal(c) => *c,
+                    _ => unreachable!(),
+                })
+                .collect())
+        } else {
+            Segment::Pattern(tokens)
+        }
+    }
+
+    fn matches(&self, name: &[char]) -> bool {
+        match self {
+            Segment::Literal(literal) => literal.chars().eq(name.iter().copied()),
+            Segment::Globstar => name.first() != Some(&'.'),
+            Segment::Pattern(tokens) => {
+                if name.first() == Some(&'.') && tokens.first() != Some(&Token::Literal('.')) {
+                    return false;
+                }
+
+                match_tokens(tokens, name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(char),
+    Any,
+    Star,
+    Class { negated: bool, ranges: Vec<(char, char)> },
+    Extglob { kind: char, alternatives: Vec<Vec<Token>> },
+}
+
+/// Parses tokens until end of input, or until an extglob separator if `nested` is set
+fn parse_tokens(chars: &[char], pos: &mut usize, nested: bool) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    while let Some(&c) = chars.get(*pos) {
+        if nested && (c == '|' || c == ')') {
+            break;
+        }
+
+        *pos += 1;
+
+        match c {
+            '\\' => {
+                if let Some(&escaped) = chars.get(*pos) {
+                    *pos += 1;
+                    tokens.push(Token::Literal(escaped));
+                } else {
+                    tokens.push(Token::Literal('\\'));
+                }
+            }
+            '?' | '*' | '+' | '@' | '!' if chars.get(*pos) == Some(&'(') => {
+                match parse_extglob(chars, pos) {
+                    Some(alternatives) => tokens.push(Token::Extglob { kind: c, alternatives }),
+                    None => tokens.push(match c {
+                        '*' => Token::Star,
+                        '?' => Token::Any,
+                        _ => Token::Literal(c),
+                    }),
+                }
+            }
+            '*' => {
+                if tokens.last() != Some(&Token::Star) {
+                    tokens.push(Token::Star);
+                }
+            }
+            '?' => tokens.push(Token::Any),
+            '[' => match parse_class(chars, pos) {
+                Some(class) => tokens.push(class),
+                None => tokens.push(Token::Literal('[')),
+            },
+            _ => tokens.push(Token::Literal(c)),
+        }
+    }
+
+    tokens
+}
+
+/// Parses extglob alternatives, starting on the opening parenthesis.
+/// Returns `None`, without moving, if parenthesis is not closed.
+fn parse_extglob(chars: &[char], pos: &mut usize) -> Option<Vec<Vec<Token>>> {
+    let start = *pos;
+    let mut alternatives = Vec::new();
+    *pos += 1;
+
+    loop {
+        alternatives.push(parse_tokens(chars, pos, true));
+
+        match chars.get(*pos) {
+            Some('|') => *pos += 1,
+            Some(')') => {
+                *pos += 1;
+                return Some(alternatives);
+            }
+            _ => {
+                *pos = start;
+                return None;
+            }
+        }
+    }
+}
+
+/// Parses a character class, starting after the opening bracket.
+/// Returns `None`, without moving, if bracket is not closed.
+fn parse_class(chars: &[char], pos: &mut usize) -> Option<Token> {
+    let mut idx = *pos;
+    let negated = matches!(chars.get(idx), Some('!' | '^'));
+
+    if negated {
+        idx += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut first = true;
+
+    loop {
+        let c = *chars.get(idx)?;
+        idx += 1;
+
+        if c == ']' && !first {
+            break;
+        }
+
+        first = false;
+
+        let c = if c == '\\' {
+            idx += 1;
+            *chars.get(idx - 1)?
+        } else {
+            c
+        };
+
+        if chars.get(idx) == Some(&'-') && chars.get(idx + 1).is_some_and(|&end| end != ']') {
+            ranges.push((c, chars[idx + 1]));
+            idx += 2;
+        } else {
+            ranges.push((c, c));
+        }
+    }
+
+    *pos = idx;
+    Some(Token::Class { negated, ranges })
+}
+
+fn match_tokens(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+
+    match token {
+        Token::Literal(c) => text.first() == Some(c) && match_tokens(rest, &text[1..]),
+        Token::Any => !text.is_empty() && match_tokens(rest, &text[1..]),
+        Token::Star => (0..=text.len()).any(|idx| match_tokens(rest, &text[idx..])),
+        Token::Class { negated, ranges } => text.first()
+            .is_some_and(|c| ranges.iter().any(|(start, end)| (start..=end).contains(&c)) != *negated)
+            && match_tokens(rest, &text[1..]),
+        Token::Extglob { kind, alternatives } => {
+            let matches_alternative = |part: &[char]| alternatives.iter().any(|alt| match_tokens(alt, part));
+
+            match kind {
+                '@' => (0..=text.len()).any(|idx| matches_alternative(&text[..idx]) && match_tokens(rest, &text[idx..])),
+                '?' => match_tokens(rest, text)
+                    || (0..=text.len()).any(|idx| matches_alternative(&text[..idx]) && match_tokens(rest, &text[idx..])),
+                '*' => match_repeated(alternatives, rest, text),
+                '+' => (1..=text.len()).any(|idx| matches_alternative(&text[..idx]) && match_repeated(alternatives, rest, &text[idx..])),
+                '!' => (0..=text.len()).any(|idx| !matches_alternative(&text[..idx]) && match_tokens(rest, &text[idx..])),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Matches zero or more repetitions of alternatives, followed by rest
+fn match_repeated(alternatives: &[Vec<Token>], rest: &[Token], text: &[char]) -> bool {
+    match_tokens(rest, text)
+        || (1..=text.len()).any(|idx| {
+            alternatives.iter().any(|alt| match_tokens(alt, &text[..idx]))
+                && match_repeated(alternatives, rest, &text[idx..])
+        })
+}
+
+fn match_segments(segments: &[Segment], names: &[Vec<char>]) -> bool {
+    match segments.split_first() {
+        None => names.is_empty(),
+        Some((Segment::Globstar, rest)) => {
+            match_segments(rest, names)
+                || names.first().is_some_and(|name| Segment::Globstar.matches(name) && match_segments(segments, &names[1..]))
+        }
+        Some((segment, rest)) => names.first()
+            .is_some_and(|name| segment.matches(name) && match_segments(rest, &names[1..])),
+    }
+}
+
+fn search_in(
+    fs: &dyn FileSystem,
+    root: &NormalizedPath,
+    dir: &NormalizedPath,
+    segments: &[Segment],
+    ignore: &IgnoreMatcher,
+    results: &mut BTreeSet<NormalizedPathBuf>,
+) -> ring_utils::Result<()> {
+    let Some((segment, rest)) = segments.split_first() else {
+        if !ignore.is_ignored(root, dir, fs.is_dir(dir)) {
+            results.insert(dir.to_path_buf());
+        }
+
+        return Ok(());
+    };
+
+    if let Segment::Globstar = segment {
+        search_in(fs, root, dir, rest, ignore, results)?;
+    }
+
+    let paths = match fs.read_dir(dir) {
+        Ok(paths) => paths,
+        Err(err) if matches!(err.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => return Ok(()),
+        Err(err) => return Err(Error::io(dir, err)),
+    };
+
+    for path in paths {
+        let Some(name) = path.file_name() else { continue };
+        let name = name.to_string_lossy().chars().collect::<Vec<_>>();
+
+        if !segment.matches(&name) {
+            continue;
+        }
+
+        match segment {
+            Segment::Globstar => {
+                if fs.is_dir(&path) && !ignore.is_ignored(root, &path, true) {
+                    search_in(fs, root, &path, segments, ignore, results)?;
+                }
+            }
+            _ => search_in(fs, root, &path, rest, ignore, results)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Expands braces, following bash rules: `{a,b}` lists and `{1..3}` numeric ranges are expanded,
+/// other braces are kept as is.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' => idx += 2,
+            '{' => {
+                if let Some((end, options)) = find_brace_options(&chars, idx) {
+                    let prefix = chars[..idx].iter().collect::<String>();
+                    let suffix = chars[end + 1..].iter().collect::<String>();
+
+                    return options.iter()
+                        .flat_map(|option| expand_braces(&format!("{prefix}{option}{suffix}")))
+                        .collect();
+                }
+
+                idx += 1;
+            }
+            _ => idx += 1,
+        }
+    }
+
+    vec![pattern.to_string()]
+}
+
+/// Returns index of closing brace, and options of brace starting at given index
+fn find_brace_options(chars: &[char], start: usize) -> Option<(usize, Vec<String>)> {
+    let mut depth = 0;
+    let mut options = Vec::new();
+    let mut current = String::new();
+    let mut idx = start + 1;
+
+    while let Some(&c) = chars.get(idx) {
+        match c {
+            '\\' => {
+                current.push(c);
+                current.extend(chars.get(idx + 1));
+                idx += 1;
+            }
+            '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                current.push(c);
+            }
+            '}' => {
+                options.push(current);
+
+                return if options.len() > 1 {
+                    Some((idx, options))
+                } else {
+                    expand_range(&options[0]).map(|options| (idx, options))
+                };
+            }
+            ',' if depth == 0 => options.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+
+        idx += 1;
+    }
+
+    None
+}
+
+fn expand_range(range: &str) -> Option<Vec<String>> {
+    let (start, end) = range.split_once("..")?;
+    let start = start.parse::<i64>().ok()?;
+    let end = end.parse::<i64>().ok()?;
+
+    if start <= end {
+        Some((start..=end).map(|n| n.to_string()).collect())
+    } else {
+        Some((end..=start).rev().map(|n| n.to_string()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+    use crate::RealFileSystem;
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        GlobPattern::new(pattern).matches(Path::new(path))
+    }
+
+    #[test]
+    fn it_should_expand_braces() {
+        assert_eq!(expand_braces("packages/{core,cli}"), ["packages/core", "packages/cli"]);
+        assert_eq!(expand_braces("{apps,libs/{a,b}}/*"), ["apps/*", "libs/a/*", "libs/b/*"]);
+        assert_eq!(expand_braces("v{1..3}"), ["v1", "v2", "v3"]);
+        assert_eq!(expand_braces("{single}"), ["{single}"]);
+        assert_eq!(expand_braces("{unclosed,"), ["{unclosed,"]);
+    }
+
+    #[test]
+    fn it_should_match_workspace_patterns() {
+        assert!(matches("packages/*", "packages/core"));
+        assert!(!matches("packages/*", "packages/core/sub"));
+        assert!(!matches("packages/*", "packages/.hidden"));
+        assert!(matches("packages/.*", "packages/.hidden"));
+        assert!(matches("packages/**", "packages"));
+        assert!(matches("packages/**", "packages/a/b/c"));
+        assert!(matches("**/package", "a/b/package"));
+        assert!(!matches("**/package", ".git/package"));
+        assert!(matches("components/**/ui-*", "components/forms/ui-input"));
+        assert!(matches("packages/{core,cli}", "packages/cli"));
+        assert!(matches("apps/web-?", "apps/web-1"));
+        assert!(matches("apps/v[0-9]", "apps/v2"));
+        assert!(!matches("apps/v[!0-9]", "apps/v2"));
+        assert!(matches("apps/*-[a-c]", "apps/x-b"));
+        assert!(matches("./packages/*", "packages/core"));
+        assert!(matches("/repo/packages/*", "/repo/packages/core"));
+    }
+
+    #[test]
+    fn it_should_match_extglobs() {
+        assert!(matches("packages/@(core|cli)", "packages/core"));
+        assert!(!matches("packages/@(core|cli)", "packages/web"));
+        assert!(matches("packages/!(internal)", "packages/web"));
+        assert!(!matches("packages/!(internal)", "packages/internal"));
+        assert!(matches("packages/*-?(legacy)", "packages/app-"));
+        assert!(matches("packages/*-?(legacy)", "packages/app-legacy"));
+        assert!(matches("plugins/+(a|b)", "plugins/abba"));
+        assert!(!matches("plugins/+(a|b)", "plugins/"));
+        assert!(matches("plugins/x*(ab)", "plugins/xabab"));
+        assert!(matches("plugins/unclosed@(a", "plugins/unclosed@(a"));
+    }
+
+    #[test]
+    fn it_should_parse_negated_patterns() {
+        let pattern = GlobPattern::new("!packages/**/test");
+
+        assert!(pattern.is_negated());
+        assert!(pattern.matches(Path::new("packages/core/test")));
+        assert!(!GlobPattern::new("!(internal)").is_negated());
+    }
+
+    #[test]
+    fn it_should_search_matching_paths() {
+        let dir = env::temp_dir().join(format!("ring-files-glob-{}", std::process::id())).normalize();
+        for path in ["packages/core", "packages/cli", "packages/web", "packages/web/node_modules/dep", "apps/admin/tools"] {
+            fs::create_dir_all(dir.join(path)).unwrap();
+        }
+
+        let ignore = IgnoreMatcher::default();
+        let search = |pattern: &str| GlobPattern::new(pattern)
+            .search(&RealFileSystem, &dir, &ignore)
+            .map(|paths| paths.iter()
+                .map(|path| path.as_path().strip_prefix(&dir).unwrap().display().to_string())
+                .collect::<Vec<_>>());
+
+        let braces = search("packages/{core,web,missing}");
+        let globstar = search("**/!(node_modules|apps)");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(braces.unwrap(), ["packages/core", "packages/web"]);
+        assert_eq!(globstar.unwrap(), [
+            "apps/admin", "apps/admin/tools",
+            "packages", "packages/cli", "packages/core", "packages/web"
+        ]);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{FileSystem, RealFileSystem};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::rc::Rc;
+use tracing::warn;
+
+/// Directories always ignored, whatever ignore files say
+pub const EXCLUDED_DIRS: [&str; 2] = ["node_modules", "target"];
+
+/// Ring specific ignore file, with the same syntax as `.gitignore`
+pub const RING_IGNORE_FILE: &str = ".ringignore";
+
+/// Ignore files read in each directory, from lowest to highest precedence
+const IGNORE_FILES: [&str; 3] = [".gitignore", ".ignore", RING_IGNORE_FILE];
+
+/// Tells if a path is ignored, according to ignore files found in its ancestors and to git's
+/// global excludes file.
+///
+/// Ignore files of each directory, and global excludes of each searched root, are loaded once
+/// and cached.
+#[derive(Debug)]
+pub struct IgnoreMatcher {
+    fs: Rc<dyn FileSystem>,
+    cache: RefCell<BTreeMap<NormalizedPathBuf, Rc<Gitignore>>>,
+    globals: RefCell<BTreeMap<NormalizedPathBuf, Rc<Gitignore>>>,
+}
+
+impl IgnoreMatcher {
+    pub fn new(fs: Rc<dyn FileSystem>) -> IgnoreMatcher {
+        IgnoreMatcher {
+            fs,
+            cache: RefCell::new(BTreeMap::new()),
+            globals: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns `true` if given path, found while searching in root, is excluded by itself or
+    /// through one of its parents. Nearest ignore files take precedence.
+    ///
+    /// Only directories below root are checked against [`EXCLUDED_DIRS`] and git's global
+    /// excludes, which are relative to root.
+    pub fn is_ignored(&self, root: &NormalizedPath, path: &NormalizedPath, is_dir: bool) -> bool {
+        let relative = path.as_path().strip_prefix(root).ok();
+
+        let is_excluded = relative.is_some_and(|relative| relative.components()
+            .any(|component| component.as_os_str().to_str().is_some_and(|name| EXCLUDED_DIRS.contains(&name))));
+
+        if is_excluded {
+            return true;
+        }
+
+        for dir in path.ancestors().skip(1) {
+            match self.load(dir).matched_path_or_any_parents(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => continue,
+            }
+        }
+
+        relative.is_some_and(|relative| !relative.as_os_str().is_empty()
+            && self.global(root).matched_path_or_any_parents(relative, is_dir).is_ignore())
+    }
+
+    fn global(&self, root: &NormalizedPath) -> Rc<Gitignore> {
+        if let Some(matcher) = self.globals.borrow().get(root) {
+            return matcher.clone();
+        }
+
+        let (global, err) = GitignoreBuilder::new(root).build_global();
+
+        if let Some(err) = err {
+            warn!("Error while loading global git excludes: {err}");
+        }
+
+        let matcher = Rc::new(global);
+        self.globals.borrow_mut().insert(root.to_path_buf(), matcher.clone());
+        matcher
+    }
+
+    fn load(&self, dir: &NormalizedPath) -> Rc<Gitignore> {
+        if let Some(matcher) = self.cache.borrow().get(dir) {
+            return matcher.clone();
+        }
+
+        let mut builder = GitignoreBuilder::new(dir);
+
+        for file in IGNORE_FILES {
+            let path = dir.join(file);
+
+            let content = match self.fs.read_to_string(&path) {
+                Ok(content) => content,
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => {
+                    warn!("Error while loading {}: {err}", path.display());
+                    continue;
+                }
+            };
+
+            for line in content.lines() {
+                if let Err(err) = builder.add_line(Some(path.as_path().to_path_buf()), line) {
+                    warn!("Error while loading {}: {err}", path.display());
+                }
+            }
+        }
+
+        let matcher = Rc::new(builder.build().unwrap_or_else(|err| {
+            warn!("Error while loading ignore files in {}: {err}", dir.display());
+            Gitignore::empty()
+        }));
+
+        self.cache.borrow_mut().insert(dir.to_path_buf(), matcher.clone());
+        matcher
+    }
+}
+
+impl Default for IgnoreMatcher {
+    fn default() -> Self {
+        IgnoreMatcher::new(Rc::new(RealFileSystem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+    use std::path::Path;
+    use ring_utils::Normalize;
+    use crate::MemoryFileSystem;
+    use super::*;
+
+    #[test]
+    fn it_should_match_ignore_files_of_ancestors() {
+        let dir = env::temp_dir().join(format!("ring-files-ignore-{}", std::process::id())).normalize();
+        fs::create_dir_all(dir.join("packages/api/dist")).unwrap();
+        fs::write(dir.join(".gitignore"), "dist\n*.log\n").unwrap();
+        fs::write(dir.join(".ringignore"), "fixtures\n").unwrap();
+        fs::write(dir.join("packages/.ignore"), "!debug.log\n").unwrap();
+
+        let matcher = IgnoreMatcher::default();
+        let results = [
+            matcher.is_ignored(&dir, &dir.join("packages/api"), true),
+            matcher.is_ignored(&dir, &dir.join("packages/api/dist"), true),
+            matcher.is_ignored(&dir, &dir.join("packages/api/dist/index.js"), false),
+            matcher.is_ignored(&dir, &dir.join("packages/fixtures"), true),
+            matcher.is_ignored(&dir, &dir.join("packages/error.log"), false),
+            matcher.is_ignored(&dir, &dir.join("packages/debug.log"), false),
+            matcher.is_ignored(&dir, &dir.join("packages/node_modules/dep"), true),
+        ];
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(results, [false, true, true, true, true, false, true]);
+    }
+
+    #[test]
+    fn it_should_only_exclude_directories_below_root() {
+        let root = Path::new("/tmp/target/ws").normalize();
+        let matcher = IgnoreMatcher::new(Rc::new(MemoryFileSystem::new()));
+
+        assert!(!matcher.is_ignored(&root, &root.join("packages/api"), true));
+        assert!(matcher.is_ignored(&root, &root.join("packages/target"), true));
+    }
+}
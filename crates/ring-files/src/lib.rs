@@ -1,5 +1,17 @@
+mod file_system;
+mod git_file_system;
+mod glob_pattern;
+mod ignore_matcher;
 mod manifest_loader;
+mod memory_file_system;
 mod pattern_iterator;
+mod walker;
 
+pub use file_system::{FileKind, FileSystem, RealFileSystem};
+pub use git_file_system::GitFileSystem;
+pub use glob_pattern::GlobPattern;
+pub use ignore_matcher::{EXCLUDED_DIRS, IgnoreMatcher, RING_IGNORE_FILE};
 pub use manifest_loader::ManifestLoader;
+pub use memory_file_system::MemoryFileSystem;
 pub use pattern_iterator::{PatternIterator, ResolvedPatterns};
+pub use walker::Walker;
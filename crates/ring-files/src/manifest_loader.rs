@@ -1,28 +1,30 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::fs::File;
 use std::io::ErrorKind;
 use std::rc::Rc;
-use anyhow::{anyhow, Context};
 use tracing::{debug, trace};
 use ring_traits::Manifest;
-use ring_utils::{NormalizedPath, OptionalResult, PathTree};
+use ring_utils::{Error, NormalizedPath, OptionalResult, PathTree};
+use crate::FileSystem;
 
 #[derive(Debug)]
 pub struct ManifestLoader<M : Manifest> {
-    filename: &'static str,
+    filename: Cow<'static, str>,
+    fs: Rc<dyn FileSystem>,
     cache: RefCell<PathTree<Option<Rc<M>>>>,
 }
 
 impl<M : Manifest> ManifestLoader<M> {
-    pub fn new(filename: &'static str) -> ManifestLoader<M> {
+    pub fn new(filename: impl Into<Cow<'static, str>>, fs: Rc<dyn FileSystem>) -> ManifestLoader<M> {
         ManifestLoader {
-            filename,
+            filename: filename.into(),
+            fs,
             cache: RefCell::new(PathTree::new())
         }
     }
 
     pub fn load(&self, path: &NormalizedPath) -> OptionalResult<Rc<M>> {
-        let path = path.join(self.filename);
+        let path = path.join(self.filename.as_ref());
         
         if let Some(result) = self.cache.borrow().get(&path) {
             if result.is_some() {
@@ -33,23 +35,25 @@ impl<M : Manifest> ManifestLoader<M> {
         }
         
         trace!("Try loading {}", path.display());
-        match File::open(&path) {
-            Ok(ref mut file) => {
-                M::from_reader(file)
+        match self.fs.read_to_string(&path) {
+            Ok(content) => {
+                M::from_str(&content)
                     .map(|mnf| Rc::new(mnf))
                     .inspect(|mnf| {
                         debug!("Loaded manifest at {}", path.display());
                         self.cache.borrow_mut().set(&path, Some(mnf.clone()));
                     })
-                    .with_context(|| format!("Error while parsing {}", path.display())).into()
+                    .map_err(|err| match err {
+                        Error::Manifest(err) => err.with_path(path.clone()).with_content(content).into(),
+                        err => err.context(format!("Error while parsing {}", path.display())),
+                    })
+                    .into()
             }
             Err(err) if err.kind() == ErrorKind::NotFound => {
                 self.cache.borrow_mut().set(&path, None);
                 OptionalResult::Empty
             }
-            Err(err) => OptionalResult::Fail(
-                anyhow!(err).context(format!("Unable to access {}", path.display()))
-            )
+            Err(err) => OptionalResult::Fail(Error::io(&path, err))
         }
     }
 }
@@ -0,0 +1,119 @@
+use crate::{FileKind, FileSystem};
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::io::ErrorKind;
+
+/// Tree of known files and directories, shared by in-memory file systems
+#[derive(Debug, Default)]
+pub(crate) struct PathIndex {
+    files: BTreeSet<NormalizedPathBuf>,
+    /// Entries of each directory
+    dirs: BTreeMap<NormalizedPathBuf, BTreeSet<NormalizedPathBuf>>,
+}
+
+impl PathIndex {
+    /// Adds a file, and all its ancestors as directories
+    pub(crate) fn insert_file(&mut self, path: &NormalizedPath) {
+        for (entry, dir) in path.ancestors().zip(path.ancestors().skip(1)) {
+            self.dirs.entry(dir.to_path_buf()).or_default().insert(entry.to_path_buf());
+        }
+
+        self.files.insert(path.to_path_buf());
+    }
+
+    pub(crate) fn kind(&self, path: &NormalizedPath) -> Option<FileKind> {
+        if self.files.contains(path) {
+            Some(FileKind::File)
+        } else if self.dirs.contains_key(path) {
+            Some(FileKind::Dir)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn read_dir(&self, path: &NormalizedPath) -> io::Result<Vec<NormalizedPathBuf>> {
+        match self.dirs.get(path) {
+            Some(entries) => Ok(entries.iter().cloned().collect()),
+            None if self.files.contains(path) => Err(io::Error::new(ErrorKind::NotADirectory, format!("{} is not a directory", path.display()))),
+            None => Err(not_found(path)),
+        }
+    }
+}
+
+pub(crate) fn not_found(path: &NormalizedPath) -> io::Error {
+    io::Error::new(ErrorKind::NotFound, format!("{} not found", path.display()))
+}
+
+/// File system held in memory, mostly useful to test detectors
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use ring_files::{FileSystem, MemoryFileSystem};
+/// use ring_utils::Normalize;
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.add_file(&Path::new("/repo/package.json").normalize(), r#"{ "name": "test" }"#);
+///
+/// assert!(fs.is_dir(&Path::new("/repo").normalize()));
+/// assert!(fs.is_file(&Path::new("/repo/package.json").normalize()));
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    index: PathIndex,
+    contents: BTreeMap<NormalizedPathBuf, String>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> MemoryFileSystem {
+        Default::default()
+    }
+
+    /// Adds a file with given content, replacing any previous one
+    pub fn add_file(&mut self, path: &NormalizedPath, content: impl Into<String>) {
+        self.index.insert_file(path);
+        self.contents.insert(path.to_path_buf(), content.into());
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &NormalizedPath) -> io::Result<String> {
+        self.contents.get(path).cloned().ok_or_else(|| not_found(path))
+    }
+
+    fn kind(&self, path: &NormalizedPath) -> io::Result<Option<FileKind>> {
+        Ok(self.index.kind(path))
+    }
+
+    fn read_dir(&self, path: &NormalizedPath) -> io::Result<Vec<NormalizedPathBuf>> {
+        self.index.read_dir(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use ring_utils::Normalize;
+    use super::*;
+
+    #[test]
+    fn it_should_list_directory_entries() {
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&Path::new("/repo/packages/b/package.json").normalize(), "{}");
+        fs.add_file(&Path::new("/repo/packages/a/package.json").normalize(), "{}");
+        fs.add_file(&Path::new("/repo/package.json").normalize(), "{}");
+
+        assert_eq!(fs.read_dir(&Path::new("/repo").normalize()).unwrap(), [
+            Path::new("/repo/package.json").normalize(),
+            Path::new("/repo/packages").normalize(),
+        ]);
+        assert_eq!(fs.read_dir(&Path::new("/repo/packages").normalize()).unwrap(), [
+            Path::new("/repo/packages/a").normalize(),
+            Path::new("/repo/packages/b").normalize(),
+        ]);
+        assert_eq!(fs.read_dir(&Path::new("/missing").normalize()).unwrap_err().kind(), ErrorKind::NotFound);
+        assert_eq!(fs.read_to_string(&Path::new("/repo/package.json").normalize()).unwrap(), "{}");
+    }
+}
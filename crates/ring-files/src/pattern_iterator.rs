@@ -1,9 +1,12 @@
-use anyhow::anyhow;
+use std::collections::BTreeSet;
+use std::env;
 use std::iter::FusedIterator;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use tracing::debug;
 use ring_traits::DetectAs;
-use ring_utils::{Normalize, NormalizedPath, NormalizedPathBuf, OptionalResult};
+use ring_utils::{Error, Normalize, NormalizedPath, NormalizedPathBuf, OptionalResult};
+use crate::{FileSystem, GlobPattern, IgnoreMatcher};
 
 pub trait PatternIterator : Iterator {
     /// Uses given detector on each emitted normalized path
@@ -16,15 +19,29 @@ pub trait PatternIterator : Iterator {
         DetectedAt::new(self, detector)
     }
 
-    /// Uses the glob crate to search files matching each emitted pattern
-    #[cfg(feature = "glob")]
+    /// Uses given detector on each emitted normalized path, passing errors through
     #[inline]
-    fn glob_search(self) -> GlobSearch<Self>
+    fn try_detect_at<P, T>(self, detector: Rc<dyn DetectAs<T>>) -> TryDetectedAt<Self, T>
+    where
+        Self: Sized + Iterator<Item = ring_utils::Result<P>>,
+        P: AsRef<NormalizedPath>
+    {
+        TryDetectedAt::new(self, detector)
+    }
+
+    /// Searches files matching each emitted pattern in given file system, see [`GlobPattern`] for
+    /// supported syntax.
+    /// Negated patterns exclude matching results of every other pattern.
+    ///
+    /// Paths excluded by ignore files, and paths inside `node_modules` or `target` directories
+    /// are skipped.
+        #[inline]
+    fn glob_search(self, fs: Rc<dyn FileSystem>) -> GlobSearch<Self>
     where
         Self: Sized,
-        Self::Item: AsRef<NormalizedPath>
+        Self::Item: AsRef<Path>
     {
-        GlobSearch::new(self)
+        GlobSearch::new(self, fs)
     }
 
     /// Prepends each patterns with given base
@@ -34,7 +51,7 @@ pub trait PatternIterator : Iterator {
     /// Basic usage:
     /// 
     /// ```
-    /// use std::path::Path;
+    /// use std::path::{Path, PathBuf};
     /// use ring_files::PatternIterator;
     /// use ring_utils::Normalize;
     ///
@@ -42,13 +59,13 @@ pub trait PatternIterator : Iterator {
     /// let patterns = vec!["crates/*", "scripts"];
     /// let prepended = patterns.iter().resolve(&base).collect::<Vec<_>>();
     ///
-    /// assert_eq!(prepended, &["/example/crates/*", "/example/scripts"]);
+    /// assert_eq!(prepended, &[Path::new("/example/crates/*"), Path::new("/example/scripts")]);
     /// ```
     /// 
     /// It does not prepend absolute patterns:
     /// 
     /// ```
-    /// use std::path::Path;
+    /// use std::path::{Path, PathBuf};
     /// use ring_files::PatternIterator;
     /// use ring_utils::Normalize;
     ///
@@ -56,10 +73,24 @@ pub trait PatternIterator : Iterator {
     /// let patterns = vec!["/crates/*", "/scripts"];
     /// let prepended = patterns.iter().resolve(&base).collect::<Vec<_>>();
     ///
-    /// assert_eq!(prepended, &["/crates/*", "/scripts"]);
+    /// assert_eq!(prepended, &[Path::new("/crates/*"), Path::new("/scripts")]);
+    /// ```
+    ///
+    /// It keeps negation of negated patterns:
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use ring_files::PatternIterator;
+    /// use ring_utils::Normalize;
+    ///
+    /// let base = Path::new("/example").normalize();
+    /// let patterns = vec!["crates/*", "!crates/internal"];
+    /// let prepended = patterns.iter().resolve(&base).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(prepended, &[Path::new("/example/crates/*"), Path::new("!/example/crates/internal")]);
     /// ```
     #[inline]
-    fn resolve(self, base: &NormalizedPath) -> ResolvedPatterns<Self>
+    fn resolve(self, base: &NormalizedPath) -> ResolvedPatterns<'_, Self>
     where
         Self: Sized,
         Self::Item: AsRef<Path>
@@ -91,7 +122,7 @@ impl<I: Iterator, T> Iterator for DetectedAt<I, T>
 where
     I::Item: AsRef<NormalizedPath>
 {
-    type Item = anyhow::Result<T>;
+    type Item = ring_utils::Result<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -111,70 +142,135 @@ where
     I::Item: AsRef<NormalizedPath>
 {}
 
-#[cfg(feature = "glob")]
+pub struct TryDetectedAt<I: Iterator, T> {
+    iter: I,
+    detector: Rc<dyn DetectAs<T>>
+}
+
+impl<I: Iterator, T> TryDetectedAt<I, T> {
+    fn new(iter: I, detector: Rc<dyn DetectAs<T>>) -> TryDetectedAt<I, T> {
+        TryDetectedAt { iter, detector }
+    }
+}
+
+impl<I, P, T> Iterator for TryDetectedAt<I, T>
+where
+    I: Iterator<Item = ring_utils::Result<P>>,
+    P: AsRef<NormalizedPath>
+{
+    type Item = ring_utils::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let path = match self.iter.next()? {
+                Ok(path) => path,
+                Err(err) => return Some(Err(err)),
+            };
+
+            match self.detector.detect_at_as(path.as_ref()) {
+                OptionalResult::Found(item) => return Some(Ok(item)),
+                OptionalResult::Fail(err) => return Some(Err(err)),
+                OptionalResult::Empty => continue
+            }
+        }
+    }
+}
+
+impl<I, P, T> FusedIterator for TryDetectedAt<I, T>
+where
+    I: FusedIterator<Item = ring_utils::Result<P>>,
+    P: AsRef<NormalizedPath>
+{}
+
 pub struct GlobSearch<I: Iterator>
 where
-    I::Item: AsRef<NormalizedPath>
+    I::Item: AsRef<Path>
 {
-    iter: I,
-    paths: Option<glob::Paths>,
+    iter: Option<I>,
+    fs: Rc<dyn FileSystem>,
+    results: std::vec::IntoIter<ring_utils::Result<NormalizedPathBuf>>,
 }
 
-#[cfg(feature = "glob")]
 impl<I: Iterator> GlobSearch<I>
 where
-    I::Item: AsRef<NormalizedPath>
+    I::Item: AsRef<Path>
 {
-    fn new(iter: I) -> GlobSearch<I> {
+    fn new(iter: I, fs: Rc<dyn FileSystem>) -> GlobSearch<I> {
         GlobSearch {
-            iter,
-            paths: None,
+            iter: Some(iter),
+            fs,
+            results: Vec::new().into_iter(),
         }
     }
-}
 
-#[cfg(feature = "glob")]
-impl<I: Iterator> Iterator for GlobSearch<I>
-where
-    I::Item: AsRef<NormalizedPath>
-{
-    type Item = anyhow::Result<NormalizedPathBuf>;
+    /// Negated patterns apply to every other pattern, so all of them must be known before searching
+    fn search(&self, iter: I) -> Vec<ring_utils::Result<NormalizedPathBuf>> {
+        let mut patterns = Vec::new();
+        let mut results = Vec::new();
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if let Some(paths) = &mut self.paths {
-                match paths.next() {
-                    Some(Ok(path)) => break Some(Ok(path.normalize())),
-                    Some(Err(err)) => {
-                        let context = format!("Unable to access {}", err.path().display());
-                        break Some(Err(anyhow!(err.into_error()).context(context)))
-                    },
-                    None => {
-                        self.paths = None;
-                    }
-                }
+        for pattern in iter {
+            match pattern.as_ref().as_os_str().to_str() {
+                Some(str) => patterns.push(GlobPattern::new(str)),
+                None => results.push(Err(Error::pattern(pattern.as_ref().display(), "pattern is not valid utf-8"))),
             }
+        }
 
-            if let Some(pattern) = self.iter.next() {
-                match glob::glob(pattern.as_ref().as_os_str().to_str()?) {
-                    Ok(paths) => {
-                        self.paths = Some(paths);
+        let (negated, patterns): (Vec<_>, Vec<_>) = patterns.into_iter()
+            .partition(GlobPattern::is_negated);
+
+        // Relative patterns are resolved from current directory
+        let current_dir = match env::current_dir() {
+            Ok(current_dir) => current_dir.normalize(),
+            Err(err) => {
+                results.push(Err(Error::io(".", err)));
+                return results;
+            }
+        };
+
+        let ignore = IgnoreMatcher::new(self.fs.clone());
+        let mut found = BTreeSet::new();
+
+        for pattern in patterns {
+            match pattern.search(self.fs.as_ref(), &current_dir, &ignore) {
+                Ok(paths) => {
+                    // Ignored paths are already skipped by search
+                    for path in paths {
+                        if negated.iter().any(|negated| negated.matches(path.as_path())) {
+                            debug!("Excluded {}", path.display());
+                            continue;
+                        }
+
+                        if found.insert(path.clone()) {
+                            results.push(Ok(path));
+                        }
                     }
-                    Err(err) => break Some(Err(
-                        anyhow!(err).context(format!("Error while parsing pattern {}", pattern.as_ref().display()))
-                    )),
                 }
-            } else {
-                break None;
+                Err(err) => results.push(Err(err)),
             }
         }
+
+        results
     }
 }
 
-#[cfg(feature = "glob")]
-impl<I: FusedIterator> FusedIterator for GlobSearch<I>
+impl<I: Iterator> Iterator for GlobSearch<I>
 where
-    I::Item: AsRef<NormalizedPath>
+    I::Item: AsRef<Path>
+{
+    type Item = ring_utils::Result<NormalizedPathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.results = self.search(iter).into_iter();
+        }
+
+        self.results.next()
+    }
+}
+
+impl<I: Iterator> FusedIterator for GlobSearch<I>
+where
+    I::Item: AsRef<Path>
 {}
 
 pub struct ResolvedPatterns<'a, I: Iterator>
@@ -194,8 +290,13 @@ where
     }
 
     #[inline]
-    fn prepend_pattern(&self, pattern: I::Item) -> NormalizedPathBuf {
-        pattern.as_ref().resolve(self.base)
+    fn prepend_pattern(&self, pattern: I::Item) -> PathBuf {
+        match pattern.as_ref().to_str().and_then(|pattern| pattern.strip_prefix('!')) {
+            Some(negated) if !negated.starts_with('(') => {
+                PathBuf::from(format!("!{}", Path::new(negated).resolve(self.base).display()))
+            }
+            _ => pattern.as_ref().resolve(self.base).as_path().to_path_buf(),
+        }
     }
 }
 
@@ -203,7 +304,7 @@ impl<'a, I: Iterator> Iterator for ResolvedPatterns<'a, I>
 where
     I::Item: AsRef<Path>
 {
-    type Item = NormalizedPathBuf;
+    type Item = PathBuf;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|pattern| self.prepend_pattern(pattern))
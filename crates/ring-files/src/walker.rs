@@ -0,0 +1,89 @@
+use crate::{FileSystem, IgnoreMatcher};
+use ring_utils::{Error, NormalizedPath, NormalizedPathBuf};
+use std::iter::FusedIterator;
+use std::rc::Rc;
+
+/// Walks every directory under a root in given file system, in file name order.
+///
+/// Walk skips hidden directories, `node_modules` and `target` directories, and anything excluded
+/// by `.gitignore`, `.ignore` or `.ringignore` files, or by git's global excludes file.
+pub struct Walker {
+    fs: Rc<dyn FileSystem>,
+    root: NormalizedPathBuf,
+    ignore: IgnoreMatcher,
+    pending: Vec<NormalizedPathBuf>,
+}
+
+impl Walker {
+    pub fn new(fs: Rc<dyn FileSystem>, root: &NormalizedPath) -> Walker {
+        Walker {
+            ignore: IgnoreMatcher::new(fs.clone()),
+            fs,
+            root: root.to_path_buf(),
+            pending: vec![root.to_path_buf()],
+        }
+    }
+
+    fn is_walked(&self, path: &NormalizedPath) -> bool {
+        let is_hidden = path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+
+        !is_hidden && self.fs.is_dir(path) && !self.ignore.is_ignored(&self.root, path, true)
+    }
+}
+
+impl Iterator for Walker {
+    type Item = ring_utils::Result<NormalizedPathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.pending.pop()?;
+
+        let mut children = match self.fs.read_dir(&dir) {
+            Ok(children) => children,
+            Err(err) => return Some(Err(Error::io(&dir, err).context("Error while walking directories"))),
+        };
+        children.sort();
+
+        // Pushed in reverse order, to be popped in file name order
+        for child in children.into_iter().rev() {
+            if self.is_walked(&child) {
+                self.pending.push(child);
+            }
+        }
+
+        Some(Ok(dir))
+    }
+}
+
+impl FusedIterator for Walker {}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+    use ring_utils::Normalize;
+    use crate::RealFileSystem;
+    use super::*;
+
+    #[test]
+    fn it_should_walk_directories_not_ignored() {
+        let dir = env::temp_dir().join(format!("ring-files-walker-{}", std::process::id())).normalize();
+        fs::create_dir_all(dir.join("packages/api/dist")).unwrap();
+        fs::create_dir_all(dir.join("packages/api/node_modules/dep")).unwrap();
+        fs::create_dir_all(dir.join("packages/web")).unwrap();
+        fs::create_dir_all(dir.join("crates/core/target/debug")).unwrap();
+        fs::create_dir_all(dir.join("fixtures/broken")).unwrap();
+        fs::create_dir_all(dir.join(".cache/data")).unwrap();
+        fs::write(dir.join(".gitignore"), "dist\n").unwrap();
+        fs::write(dir.join(".ringignore"), "fixtures\n").unwrap();
+
+        let result = Walker::new(Rc::new(RealFileSystem), &dir)
+            .map(|path| path.map(|path| path.as_path().strip_prefix(&dir).unwrap().to_path_buf()))
+            .collect::<ring_utils::Result<Vec<_>>>();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result.unwrap(), [
+            "", "crates", "crates/core", "packages", "packages/api", "packages/web",
+        ].map(std::path::PathBuf::from));
+    }
+}
@@ -0,0 +1,30 @@
+//! Global git excludes depend on the environment and the current directory, so this test runs in
+//! its own process.
+
+use std::{env, fs};
+use ring_files::IgnoreMatcher;
+use ring_utils::Normalize;
+
+#[test]
+fn it_should_apply_global_excludes_from_a_subdirectory() {
+    let dir = env::temp_dir().join(format!("ring-files-global-excludes-{}", std::process::id())).normalize();
+    let root = dir.join("repo");
+    fs::create_dir_all(root.join("packages/api")).unwrap();
+    fs::create_dir_all(root.join("apps/web")).unwrap();
+    fs::write(dir.join("excludes"), "*.log\n/apps\n").unwrap();
+    fs::write(dir.join("gitconfig"), format!("[core]\n\texcludesFile = {}\n", dir.join("excludes").display())).unwrap();
+
+    env::set_var("GIT_CONFIG_GLOBAL", dir.join("gitconfig"));
+    env::set_current_dir(root.join("packages/api")).unwrap();
+
+    let matcher = IgnoreMatcher::default();
+    let results = [
+        matcher.is_ignored(&root, &root.join("packages/api"), true),
+        matcher.is_ignored(&root, &root.join("packages/api/debug.log"), false),
+        matcher.is_ignored(&root, &root.join("apps/web"), true),
+        matcher.is_ignored(&root.join("apps"), &root.join("apps/web"), true),
+    ];
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(results, [false, true, true, false]);
+}
@@ -10,4 +10,8 @@ pub const PACKAGE_MANAGERS: [PackageManager; 3] = [
     PackageManager::Yarn,
 ];
 
-pub const JS_TAG: Tag = Tag::with_color("js", Ansi(AnsiColors::Yellow));
\ No newline at end of file
+pub const JS_TAG: Tag = Tag::with_color("js", Ansi(AnsiColors::Yellow));
+pub const TYPESCRIPT_TAG: Tag = Tag::with_color("typescript", Ansi(AnsiColors::Blue));
+pub const BIN_TAG: Tag = Tag::with_color("bin", Ansi(AnsiColors::Green));
+pub const LIB_TAG: Tag = Tag::with_color("lib", Ansi(AnsiColors::Cyan));
+pub const PRIVATE_TAG: Tag = Tag::with_color("private", Ansi(AnsiColors::BrightBlack));
\ No newline at end of file
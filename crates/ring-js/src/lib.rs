@@ -1,20 +1,30 @@
 mod constants;
 mod lockfile_detector;
+mod npm_lockfile;
+mod npm_range;
 mod package_manager;
 mod package_manifest;
+mod parse_errors;
+mod pnpm_lockfile;
 mod project;
 mod project_detector;
 mod scope;
 mod scope_detector;
+mod yarn_lockfile;
 
+pub use npm_lockfile::{NpmLockfile, NpmLockfilePackage};
+pub use npm_range::{parse_npm_alias, parse_npm_range};
 pub use package_manager::PackageManager;
-pub use package_manifest::PackageManifest;
+pub use package_manifest::{ModuleType, PackageBin, PackageManifest, PackageRepository};
+pub use pnpm_lockfile::{PnpmLockfile, PnpmLockfilePackage, PnpmLockfileSnapshot, PnpmResolution};
 pub use project::JsProject;
 pub use project_detector::JsProjectDetector;
 pub use scope::JsScope;
 pub use scope_detector::JsScopeDetector;
+pub use yarn_lockfile::{YarnLockfile, YarnLockfileEntry};
 use std::rc::Rc;
 use tracing::debug;
+use ring_files::{FileSystem, RealFileSystem};
 use ring_traits::{Module, ProjectDetector, ScopeDetector, TaggedDetector};
 
 // Module
@@ -26,8 +36,13 @@ pub struct JsModule {
 
 impl JsModule {
     pub fn new() -> JsModule {
+        JsModule::with_fs(Rc::new(RealFileSystem))
+    }
+
+    /// Builds a module detecting projects in given file system
+    pub fn with_fs(fs: Rc<dyn FileSystem>) -> JsModule {
         debug!("Initializing js module");
-        let project_detector = Rc::new(JsProjectDetector::new());
+        let project_detector = Rc::new(JsProjectDetector::new(fs));
         
         JsModule {
             project_detector: project_detector.clone(),
@@ -43,7 +58,7 @@ impl Default for JsModule {
 }
 
 impl Module for JsModule {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "js"
     }
 
@@ -1,28 +1,31 @@
 use std::cell::RefCell;
-use anyhow::anyhow;
+use std::rc::Rc;
 use tracing::{debug, info, trace};
+use ring_files::{FileSystem, RealFileSystem};
 use ring_traits::{detect_from, Detect};
-use ring_utils::{NormalizedPath, OptionalResult, PathTree};
+use ring_utils::{Error, NormalizedPath, OptionalResult, PathTree};
 use ring_utils::OptionalResult::{Empty, Fail, Found};
 use crate::constants::PACKAGE_MANAGERS;
 use crate::PackageManager;
 
 #[derive(Debug)]
 pub struct JsLockfileDetector {
-    cache: RefCell<PathTree<PackageManager>>
+    cache: RefCell<PathTree<PackageManager>>,
+    fs: Rc<dyn FileSystem>,
 }
 
 impl JsLockfileDetector {
-    pub fn new() -> JsLockfileDetector {
+    pub fn new(fs: Rc<dyn FileSystem>) -> JsLockfileDetector {
         JsLockfileDetector {
-            cache: RefCell::new(PathTree::new())
+            cache: RefCell::new(PathTree::new()),
+            fs,
         }
     }
 }
 
 impl Default for JsLockfileDetector {
     fn default() -> Self {
-        JsLockfileDetector::new()
+        JsLockfileDetector::new(Rc::new(RealFileSystem))
     }
 }
 
@@ -30,7 +33,7 @@ impl Detect for JsLockfileDetector {
     type Item = PackageManager;
 
     fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
-        let path = if path.is_file() { path.parent().unwrap() } else { path };
+        let path = if self.fs.is_file(path) { path.parent().unwrap() } else { path };
 
         if let Some(&pm) = self.cache.borrow().get(path) {
             debug!("Found {} lockfile at {} (cached)", pm, path.display());
@@ -41,7 +44,7 @@ impl Detect for JsLockfileDetector {
             let lockfile = path.join(package_manager.lockfile());
             trace!("Testing {}", lockfile.display());
 
-            match lockfile.try_exists() {
+            match self.fs.try_exists(&lockfile) {
                 Ok(true) => {
                     debug!("Found {} lockfile at {}", package_manager, path.display());
                     self.cache.borrow_mut().set(path, package_manager);
@@ -50,7 +53,7 @@ impl Detect for JsLockfileDetector {
                 }
                 Ok(false) => continue,
                 Err(err) => {
-                    return Fail(anyhow!(err).context(format!("Unable to access {}", lockfile.display())));
+                    return Fail(Error::io(&lockfile, err));
                 }
             }
         }
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use ring_traits::{Manifest, PackageRef, ResolvedPackage};
+use ring_utils::ManifestError;
+use crate::parse_errors::json_error;
+
+const NODE_MODULES: &str = "node_modules/";
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NpmLockfilePackage {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub resolved: Option<String>,
+    #[serde(default)]
+    pub integrity: Option<String>,
+    #[serde(default)]
+    pub link: bool,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub peer_dependencies: BTreeMap<String, String>,
+}
+
+/// Content of a `package-lock.json` file (v2 or v3)
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NpmLockfile {
+    pub lockfile_version: u32,
+    #[serde(default)]
+    pub packages: BTreeMap<String, NpmLockfilePackage>,
+}
+
+impl NpmLockfile {
+    /// Resolves `name` as node would do from the package installed at `key`
+    fn resolve(&self, key: &str, name: &str) -> Option<&str> {
+        let mut base = key;
+
+        loop {
+            let candidate = if base.is_empty() {
+                format!("{NODE_MODULES}{name}")
+            } else {
+                format!("{base}/{NODE_MODULES}{name}")
+            };
+
+            if let Some((key, _)) = self.packages.get_key_value(&candidate) {
+                return Some(key);
+            }
+
+            if base.is_empty() {
+                return None;
+            }
+
+            base = match base.rfind(NODE_MODULES) {
+                Some(0) | None => "",
+                Some(idx) => &base[..idx - 1],
+            };
+        }
+    }
+
+    /// Returns every package installed in node_modules
+    pub fn resolved_packages(&self) -> Vec<ResolvedPackage> {
+        self.packages.iter()
+            .filter(|(key, pkg)| !pkg.link && key.contains(NODE_MODULES))
+            .filter_map(|(key, pkg)| {
+                let name = pkg.name.as_deref().unwrap_or_else(|| package_name(key));
+                let version = pkg.version.as_ref()?;
+
+                let dependencies = pkg.dependencies.keys()
+                    .chain(pkg.optional_dependencies.keys())
+                    .chain(pkg.peer_dependencies.keys())
+                    .filter_map(|dep| {
+                        let installed = self.resolve(key, dep)?;
+                        let version = self.packages[installed].version.clone();
+
+                        Some(PackageRef::new(dep.clone(), version))
+                    })
+                    .collect();
+
+                Some(ResolvedPackage {
+                    name: name.to_string(),
+                    version: version.clone(),
+                    source: pkg.resolved.clone(),
+                    checksum: pkg.integrity.clone(),
+                    dependencies,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Extracts package name from its install path
+fn package_name(key: &str) -> &str {
+    key.rfind(NODE_MODULES)
+        .map_or(key, |idx| &key[idx + NODE_MODULES.len()..])
+}
+
+impl Manifest for NpmLockfile {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        let lockfile: NpmLockfile = serde_json::from_str(content)
+            .map_err(|err| json_error(&err))?;
+
+        if lockfile.lockfile_version < 2 {
+            return Err(ManifestError::new(format!("unsupported npm lockfile version {}", lockfile.lockfile_version))
+                .with_key("lockfileVersion")
+                .into());
+        }
+
+        Ok(lockfile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKFILE: &str = r#"{
+        "name": "test",
+        "lockfileVersion": 3,
+        "packages": {
+            "": {
+                "name": "test",
+                "workspaces": ["packages/*"]
+            },
+            "node_modules/@acme/ui": {
+                "resolved": "packages/ui",
+                "link": true
+            },
+            "node_modules/react": {
+                "version": "18.3.1",
+                "resolved": "https://registry.npmjs.org/react/-/react-18.3.1.tgz",
+                "integrity": "sha512-react",
+                "dependencies": { "loose-envify": "^1.1.0" }
+            },
+            "node_modules/loose-envify": {
+                "version": "1.4.0",
+                "dependencies": { "js-tokens": "^3.0.0 || ^4.0.0" }
+            },
+            "node_modules/js-tokens": {
+                "version": "4.0.0"
+            },
+            "node_modules/loose-envify/node_modules/js-tokens": {
+                "version": "3.0.2"
+            },
+            "packages/ui": {
+                "name": "@acme/ui",
+                "dependencies": { "react": "^18.0.0" }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn it_should_list_installed_packages() {
+        let lockfile = NpmLockfile::from_str(LOCKFILE).unwrap();
+        let packages = lockfile.resolved_packages();
+
+        assert_eq!(packages.len(), 4);
+        assert_eq!(packages[0].to_string(), "js-tokens@4.0.0");
+        assert_eq!(packages[1].to_string(), "loose-envify@1.4.0");
+        assert_eq!(packages[2].to_string(), "js-tokens@3.0.2");
+        assert_eq!(packages[3], ResolvedPackage {
+            name: "react".to_string(),
+            version: "18.3.1".to_string(),
+            source: Some("https://registry.npmjs.org/react/-/react-18.3.1.tgz".to_string()),
+            checksum: Some("sha512-react".to_string()),
+            dependencies: vec![
+                PackageRef::new("loose-envify".to_string(), Some("1.4.0".to_string())),
+            ],
+        });
+    }
+
+    #[test]
+    fn it_should_resolve_nested_node_modules_first() {
+        let lockfile = NpmLockfile::from_str(LOCKFILE).unwrap();
+        let packages = lockfile.resolved_packages();
+
+        assert_eq!(packages[1].dependencies, vec![
+            PackageRef::new("js-tokens".to_string(), Some("3.0.2".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn it_should_reject_v1_lockfiles() {
+        let lockfile = NpmLockfile::from_str(r#"{ "lockfileVersion": 1, "dependencies": {} }"#);
+
+        assert!(lockfile.is_err());
+    }
+}
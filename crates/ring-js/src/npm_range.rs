@@ -0,0 +1,156 @@
+use semver::VersionReq;
+
+/// Converts a npm version range into a list of alternative [`VersionReq`].
+///
+/// Returns `None` if the range is not a version range (tags, urls, workspace or file protocols, ...)
+pub fn parse_npm_range(range: &str) -> Option<Vec<VersionReq>> {
+    let range = range.trim();
+
+    // Aliases, like `npm:string-width@^4.2.0`
+    if let Some(rest) = range.strip_prefix("npm:") {
+        return parse_npm_range(parse_npm_alias(range).map_or(rest, |(_, range)| range));
+    }
+
+    range.split("||")
+        .map(|set| VersionReq::parse(&convert_set(set.trim())?).ok())
+        .collect()
+}
+
+/// Splits a npm alias, like `npm:string-width@^4.2.0`, into the aliased package name and its range.
+///
+/// Range is empty, so any version, when the alias has none, like `npm:string-width`.
+pub fn parse_npm_alias(range: &str) -> Option<(&str, &str)> {
+    let alias = range.trim().strip_prefix("npm:")?;
+
+    match alias.get(1..).and_then(|rest| rest.find('@')) {
+        Some(idx) => Some((&alias[..idx + 1], &alias[idx + 2..])),
+        // Yarn writes ranges with the protocol only, like `npm:^4.2.0`
+        None if parse_npm_range(alias).is_some() => None,
+        None => Some((alias, "")),
+    }
+}
+
+/// Converts a space separated comparator set into a comma separated one
+fn convert_set(set: &str) -> Option<String> {
+    if set.is_empty() {
+        return Some("*".to_string());
+    }
+
+    // Hyphen ranges, like `1.2.3 - 2.3.4`
+    if let Some((low, high)) = set.split_once(" - ") {
+        return Some(format!(">={}, <={}", convert_version(low.trim())?, convert_version(high.trim())?));
+    }
+
+    let mut comparators = Vec::new();
+    let mut operator = "";
+
+    for token in set.split_whitespace() {
+        // Operators may be separated from their version, like `>= 1.2.3`
+        if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) {
+            operator = token;
+            continue;
+        }
+
+        let split = token.find(|c: char| !matches!(c, '<' | '>' | '=' | '^' | '~')).unwrap_or(token.len());
+        let (token_operator, version) = token.split_at(split);
+        let version = convert_version(version)?;
+
+        comparators.push(match (operator, token_operator) {
+            ("", "") if version.contains(['*', 'x', 'X']) => version,
+            ("", "") => format!("={version}"),
+            ("", op) | (op, "") => format!("{op}{version}"),
+            _ => return None,
+        });
+
+        operator = "";
+    }
+
+    Some(comparators.join(", "))
+}
+
+/// Removes `v` prefix from versions, and rejects anything that does not look like a version
+fn convert_version(version: &str) -> Option<String> {
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+
+    if version.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '*' | 'x' | 'X')) {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+    use super::*;
+
+    fn matches(range: &str, version: &str) -> bool {
+        let version = Version::parse(version).unwrap();
+
+        parse_npm_range(range).unwrap()
+            .iter()
+            .any(|req| req.matches(&version))
+    }
+
+    #[test]
+    fn it_should_parse_simple_ranges() {
+        assert!(matches("^18.0.0", "18.3.1"));
+        assert!(!matches("^18.0.0", "17.0.2"));
+        assert!(matches("~1.2.0", "1.2.9"));
+        assert!(!matches("~1.2.0", "1.3.0"));
+        assert!(matches("*", "1.0.0"));
+        assert!(matches("", "1.0.0"));
+        assert!(matches("1.x", "1.9.0"));
+    }
+
+    #[test]
+    fn it_should_treat_bare_versions_as_exact() {
+        assert!(matches("5.6.3", "5.6.3"));
+        assert!(matches("v5.6.3", "5.6.3"));
+        assert!(!matches("5.6.3", "5.6.4"));
+        assert!(matches("1.2", "1.2.9"));
+        assert!(!matches("1.2", "1.3.0"));
+    }
+
+    #[test]
+    fn it_should_parse_comparator_sets() {
+        assert!(matches(">=1.2.0 <2.0.0", "1.5.0"));
+        assert!(!matches(">=1.2.0 <2.0.0", "2.0.0"));
+        assert!(matches(">= 1.2.0 < 2", "1.5.0"));
+        assert!(matches("1.2.3 - 2.3.4", "2.3.4"));
+        assert!(!matches("1.2.3 - 2.3.4", "2.3.5"));
+    }
+
+    #[test]
+    fn it_should_parse_alternatives() {
+        assert!(matches("^3.0.0 || ^4.0.0", "3.0.2"));
+        assert!(matches("^3.0.0 || ^4.0.0", "4.0.0"));
+        assert!(!matches("^3.0.0 || ^4.0.0", "5.0.0"));
+    }
+
+    #[test]
+    fn it_should_parse_aliases() {
+        assert!(matches("npm:string-width@^4.2.0", "4.2.3"));
+        assert!(matches("npm:@acme/ui@1.0.0", "1.0.0"));
+        assert!(matches("npm:string-width", "4.2.3"));
+        assert!(matches("npm:^4.2.0", "4.2.3"));
+    }
+
+    #[test]
+    fn it_should_split_aliases() {
+        assert_eq!(parse_npm_alias("npm:string-width@^4.2.0"), Some(("string-width", "^4.2.0")));
+        assert_eq!(parse_npm_alias("npm:@acme/ui@1.0.0"), Some(("@acme/ui", "1.0.0")));
+        assert_eq!(parse_npm_alias("npm:@acme/ui"), Some(("@acme/ui", "")));
+        assert_eq!(parse_npm_alias("npm:^4.2.0"), None);
+        assert_eq!(parse_npm_alias("^4.2.0"), None);
+    }
+
+    #[test]
+    fn it_should_ignore_non_version_ranges() {
+        assert_eq!(parse_npm_range("workspace:*"), None);
+        assert_eq!(parse_npm_range("latest"), None);
+        assert_eq!(parse_npm_range("file:../ui"), None);
+        assert_eq!(parse_npm_range("github:jujulego/ring"), None);
+        assert_eq!(parse_npm_range("https://example.com/ui.tgz"), None);
+    }
+}
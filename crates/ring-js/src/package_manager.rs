@@ -9,6 +9,14 @@ pub enum PackageManager {
 }
 
 impl PackageManager {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PackageManager::NPM => "npm",
+            PackageManager::PNPM => "pnpm",
+            PackageManager::Yarn => "yarn",
+        }
+    }
+
     pub fn lockfile(&self) -> &'static str {
         match self {
             PackageManager::NPM => "package-lock.json",
@@ -20,11 +28,7 @@ impl PackageManager {
 
 impl Display for PackageManager {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            PackageManager::NPM => write!(f, "npm"),
-            PackageManager::PNPM => write!(f, "pnpm"),
-            PackageManager::Yarn => write!(f, "yarn")
-        }
+        write!(f, "{}", self.name())
     }
 }
 
@@ -1,27 +1,125 @@
+use std::collections::BTreeMap;
 use std::io::Read;
-use anyhow::Context;
 use semver::Version;
 use serde::Deserialize;
+use serde_json::Value;
 use ring_traits::Manifest;
+use ring_utils::Error;
+use crate::parse_errors::json_error;
 
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct PackageManifest {
     pub name: String,
     #[serde(default)]
     pub version: Option<Version>,
     #[serde(default)]
     pub workspaces: Vec<String>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub dev_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub peer_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub scripts: BTreeMap<String, String>,
+    #[serde(default)]
+    pub bin: Option<PackageBin>,
+    #[serde(default)]
+    pub main: Option<String>,
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default, alias = "typings")]
+    pub types: Option<String>,
+    #[serde(default)]
+    pub exports: Option<Value>,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub engines: BTreeMap<String, String>,
+    #[serde(default, rename = "type")]
+    pub module_type: Option<ModuleType>,
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub repository: Option<PackageRepository>,
+    #[serde(default)]
+    pub license: Option<String>,
+}
+
+impl PackageManifest {
+    /// Returns executables provided by package, by command name
+    pub fn binaries(&self) -> BTreeMap<String, String> {
+        match &self.bin {
+            Some(PackageBin::Single(path)) => {
+                // Scope is not part of the command name
+                let command = self.name.rsplit('/').next().unwrap_or(&self.name);
+                BTreeMap::from([(command.to_string(), path.clone())])
+            }
+            Some(PackageBin::Multiple(bins)) => bins.clone(),
+            None => BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if package exposes entry points to be imported by other packages
+    pub fn is_library(&self) -> bool {
+        self.main.is_some() || self.module.is_some() || self.types.is_some() || self.exports.is_some()
+    }
+}
+
+/// Value of `bin`, either a single executable named like the package, or many named ones
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PackageBin {
+    Single(String),
+    Multiple(BTreeMap<String, String>),
+}
+
+/// Value of `type`, format of `.js` files in the package
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModuleType {
+    CommonJS,
+    Module,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PackageRepository {
+    Url(String),
+    Detailed {
+        #[serde(default, rename = "type")]
+        kind: Option<String>,
+        url: String,
+        #[serde(default)]
+        directory: Option<String>,
+    },
+}
+
+impl PackageRepository {
+    pub fn url(&self) -> &str {
+        match self {
+            PackageRepository::Url(url) | PackageRepository::Detailed { url, .. } => url,
+        }
+    }
 }
 
 impl Manifest for PackageManifest {
-    fn from_str(content: &str) -> anyhow::Result<Self> {
-        serde_json::from_str(content)
-            .context("Error while parsing package manifest")
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        let mut deserializer = serde_json::Deserializer::from_str(content);
+
+        serde_path_to_error::deserialize(&mut deserializer)
+            .map_err(|err| json_error(err.inner()).with_key(err.path().to_string()).into())
     }
 
-    fn from_reader<R: Read>(reader: &mut R) -> anyhow::Result<Self> {
-        serde_json::from_reader(reader)
-            .context("Error while parsing package manifest")
+    fn from_reader<R: Read>(reader: &mut R) -> ring_utils::Result<Self> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)
+            .map_err(|err| Error::io("", err).context("Unable to read package manifest"))?;
+
+        Self::from_str(&content)
     }
 }
 
@@ -29,6 +127,16 @@ impl Manifest for PackageManifest {
 mod tests {
     use super::*;
 
+    #[test]
+    fn it_should_locate_invalid_fields() {
+        let error = PackageManifest::from_str("{\n  \"name\": \"test\",\n  \"version\": 1\n}").unwrap_err();
+        let error = error.manifest_error().unwrap();
+
+        assert_eq!(error.key(), Some("version"));
+        assert_eq!(error.line(), Some(3));
+        assert_eq!(error.explanation(), "version should be semver version, but is an integer `1`");
+    }
+
     #[test]
     fn it_should_parse_package_manifest() {
         let manifest = PackageManifest::from_str(r#"{
@@ -39,6 +147,7 @@ mod tests {
             name: "test".to_string(),
             version: None,
             workspaces: Vec::new(),
+            ..Default::default()
         });
     }
 
@@ -53,6 +162,7 @@ mod tests {
             name: "test".to_string(),
             version: Some(Version::new(1, 0, 0)),
             workspaces: Vec::new(),
+            ..Default::default()
         });
     }
 
@@ -70,6 +180,69 @@ mod tests {
                 "packages/test-a".to_string(),
                 "packages/test-b".to_string()
             ],
+            ..Default::default()
         });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn it_should_parse_package_manifest_with_dependencies() {
+        let manifest = PackageManifest::from_str(r#"{
+            "name": "test",
+            "dependencies": { "react": "^18.0.0" },
+            "devDependencies": { "typescript": "5.6.3" },
+            "peerDependencies": { "react-dom": "^18.0.0" },
+            "optionalDependencies": { "fsevents": "*" }
+        }"#);
+
+        assert_eq!(manifest.unwrap(), PackageManifest {
+            name: "test".to_string(),
+            dependencies: BTreeMap::from([("react".to_string(), "^18.0.0".to_string())]),
+            dev_dependencies: BTreeMap::from([("typescript".to_string(), "5.6.3".to_string())]),
+            peer_dependencies: BTreeMap::from([("react-dom".to_string(), "^18.0.0".to_string())]),
+            optional_dependencies: BTreeMap::from([("fsevents".to_string(), "*".to_string())]),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn it_should_parse_package_manifest_with_metadata() {
+        let manifest = PackageManifest::from_str(r#"{
+            "name": "@jujulego/ring",
+            "private": true,
+            "type": "module",
+            "bin": "./bin/ring.js",
+            "main": "./dist/index.js",
+            "typings": "./dist/index.d.ts",
+            "exports": { ".": "./dist/index.js" },
+            "scripts": { "build": "tsc" },
+            "engines": { "node": ">=20" },
+            "files": ["dist"],
+            "repository": { "type": "git", "url": "https://github.com/Jujulego/ring" },
+            "license": "MIT"
+        }"#).unwrap();
+
+        assert!(manifest.private);
+        assert!(manifest.is_library());
+        assert_eq!(manifest.module_type, Some(ModuleType::Module));
+        assert_eq!(manifest.types.as_deref(), Some("./dist/index.d.ts"));
+        assert_eq!(manifest.binaries(), BTreeMap::from([("ring".to_string(), "./bin/ring.js".to_string())]));
+        assert_eq!(manifest.scripts["build"], "tsc");
+        assert_eq!(manifest.engines["node"], ">=20");
+        assert_eq!(manifest.files, ["dist"]);
+        assert_eq!(manifest.repository.unwrap().url(), "https://github.com/Jujulego/ring");
+        assert_eq!(manifest.license.as_deref(), Some("MIT"));
+    }
+
+    #[test]
+    fn it_should_parse_package_manifest_with_many_binaries() {
+        let manifest = PackageManifest::from_str(r#"{
+            "name": "tools",
+            "bin": { "tool-a": "./a.js", "tool-b": "./b.js" },
+            "repository": "github:jujulego/tools"
+        }"#).unwrap();
+
+        assert!(!manifest.is_library());
+        assert_eq!(manifest.binaries().keys().collect::<Vec<_>>(), ["tool-a", "tool-b"]);
+        assert_eq!(manifest.repository.unwrap().url(), "github:jujulego/tools");
+    }
+}
@@ -0,0 +1,14 @@
+use ring_utils::ManifestError;
+
+/// Converts a json parse error into a manifest error, located at its line and column
+pub(crate) fn json_error(err: &serde_json::Error) -> ManifestError {
+    ManifestError::at(&err.to_string(), err.line(), err.column())
+}
+
+/// Converts a yaml parse error into a manifest error, located at its line and column
+pub(crate) fn yaml_error(err: &serde_yaml::Error) -> ManifestError {
+    match err.location() {
+        Some(location) => ManifestError::at(&err.to_string(), location.line(), location.column()),
+        None => ManifestError::new(err.to_string()),
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use ring_traits::{Manifest, PackageRef, ResolvedPackage};
+use crate::parse_errors::yaml_error;
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct PnpmResolution {
+    #[serde(default)]
+    pub integrity: Option<String>,
+    #[serde(default)]
+    pub tarball: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnpmLockfilePackage {
+    #[serde(default)]
+    pub resolution: PnpmResolution,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnpmLockfileSnapshot {
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: BTreeMap<String, String>,
+}
+
+/// Content of a `pnpm-lock.yaml` file (v6 to v9)
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnpmLockfile {
+    pub lockfile_version: String,
+    #[serde(default)]
+    pub packages: BTreeMap<String, PnpmLockfilePackage>,
+    #[serde(default)]
+    pub snapshots: BTreeMap<String, PnpmLockfileSnapshot>,
+}
+
+impl PnpmLockfile {
+    /// Returns every package resolved by pnpm
+    pub fn resolved_packages(&self) -> Vec<ResolvedPackage> {
+        // Since v9, dependencies are stored in snapshots (one per peer dependency combination)
+        let mut snapshots: BTreeMap<(&str, &str), Vec<PackageRef>> = BTreeMap::new();
+
+        for (key, snapshot) in &self.snapshots {
+            let Some(id) = parse_key(key) else { continue };
+
+            snapshots.entry(id)
+                .or_default()
+                .extend(parse_dependencies(&snapshot.dependencies, &snapshot.optional_dependencies));
+        }
+
+        let mut packages: BTreeMap<(&str, &str), ResolvedPackage> = BTreeMap::new();
+
+        for (key, package) in &self.packages {
+            let Some(id @ (name, version)) = parse_key(key) else { continue };
+
+            let resolved = packages.entry(id).or_insert_with(|| ResolvedPackage {
+                name: name.to_string(),
+                version: version.to_string(),
+                source: package.resolution.tarball.clone(),
+                checksum: package.resolution.integrity.clone(),
+                dependencies: Vec::new(),
+            });
+
+            resolved.dependencies.extend(parse_dependencies(&package.dependencies, &package.optional_dependencies));
+
+            if let Some(dependencies) = snapshots.remove(&id) {
+                resolved.dependencies.extend(dependencies);
+            }
+        }
+
+        packages.into_values()
+            .map(|mut package| {
+                package.dependencies.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+                package.dependencies.dedup();
+                package
+            })
+            .collect()
+    }
+}
+
+/// Parses a package key, like `/react@18.3.1` (v6) or `react@18.3.1(react-dom@18.3.1)` (v9)
+fn parse_key(key: &str) -> Option<(&str, &str)> {
+    let key = key.strip_prefix('/').unwrap_or(key);
+    let key = strip_peers(key);
+
+    let idx = key.get(1..)?.find('@')? + 1;
+    Some((&key[..idx], &key[idx + 1..]))
+}
+
+/// Removes peer dependencies suffix from a version
+fn strip_peers(version: &str) -> &str {
+    version.find('(').map_or(version, |idx| &version[..idx])
+}
+
+fn parse_dependencies<'a>(dependencies: &'a BTreeMap<String, String>, optional: &'a BTreeMap<String, String>) -> impl Iterator<Item = PackageRef> + 'a {
+    dependencies.iter()
+        .chain(optional)
+        .filter(|(_, version)| !version.starts_with("link:"))
+        .map(|(name, version)| {
+            let version = strip_peers(version);
+
+            // Aliased dependencies reference their target package directly
+            match parse_key(version) {
+                Some((name, version)) => PackageRef::new(name.to_string(), Some(version.to_string())),
+                None => PackageRef::new(name.clone(), Some(version.to_string())),
+            }
+        })
+}
+
+impl Manifest for PnpmLockfile {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|err| yaml_error(&err).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_packages_from_v6_lockfile() {
+        let lockfile = PnpmLockfile::from_str(r#"
+lockfileVersion: '6.0'
+
+importers:
+  .:
+    dependencies:
+      react:
+        specifier: ^18.0.0
+        version: 18.3.1
+
+packages:
+  /js-tokens@4.0.0:
+    resolution: {integrity: sha512-js-tokens}
+    dev: false
+
+  /loose-envify@1.4.0:
+    resolution: {integrity: sha512-loose-envify}
+    dependencies:
+      js-tokens: 4.0.0
+    dev: false
+
+  /react@18.3.1:
+    resolution: {integrity: sha512-react}
+    dependencies:
+      loose-envify: 1.4.0
+    dev: false
+"#).unwrap();
+
+        assert_eq!(lockfile.resolved_packages(), vec![
+            ResolvedPackage {
+                name: "js-tokens".to_string(),
+                version: "4.0.0".to_string(),
+                source: None,
+                checksum: Some("sha512-js-tokens".to_string()),
+                dependencies: vec![],
+            },
+            ResolvedPackage {
+                name: "loose-envify".to_string(),
+                version: "1.4.0".to_string(),
+                source: None,
+                checksum: Some("sha512-loose-envify".to_string()),
+                dependencies: vec![PackageRef::new("js-tokens".to_string(), Some("4.0.0".to_string()))],
+            },
+            ResolvedPackage {
+                name: "react".to_string(),
+                version: "18.3.1".to_string(),
+                source: None,
+                checksum: Some("sha512-react".to_string()),
+                dependencies: vec![PackageRef::new("loose-envify".to_string(), Some("1.4.0".to_string()))],
+            },
+        ]);
+    }
+
+    #[test]
+    fn it_should_list_packages_from_v9_lockfile() {
+        let lockfile = PnpmLockfile::from_str(r#"
+lockfileVersion: '9.0'
+
+packages:
+  '@acme/button@1.0.0':
+    resolution: {integrity: sha512-button, tarball: https://npm.acme.com/button.tgz}
+    peerDependencies:
+      react: ^18.0.0
+
+  react@18.3.1:
+    resolution: {integrity: sha512-react}
+
+snapshots:
+  '@acme/button@1.0.0(react@18.3.1)':
+    dependencies:
+      react: 18.3.1
+      string-width-cjs: string-width@4.2.3
+      utils: link:../utils
+
+  react@18.3.1: {}
+"#).unwrap();
+
+        assert_eq!(lockfile.resolved_packages(), vec![
+            ResolvedPackage {
+                name: "@acme/button".to_string(),
+                version: "1.0.0".to_string(),
+                source: Some("https://npm.acme.com/button.tgz".to_string()),
+                checksum: Some("sha512-button".to_string()),
+                dependencies: vec![
+                    PackageRef::new("react".to_string(), Some("18.3.1".to_string())),
+                    PackageRef::new("string-width".to_string(), Some("4.2.3".to_string())),
+                ],
+            },
+            ResolvedPackage {
+                name: "react".to_string(),
+                version: "18.3.1".to_string(),
+                source: None,
+                checksum: Some("sha512-react".to_string()),
+                dependencies: vec![],
+            },
+        ]);
+    }
+
+    #[test]
+    fn it_should_parse_package_keys() {
+        assert_eq!(parse_key("/react@18.3.1"), Some(("react", "18.3.1")));
+        assert_eq!(parse_key("@acme/ui@1.0.0(react@18.3.1)"), Some(("@acme/ui", "1.0.0")));
+        assert_eq!(parse_key("18.3.1"), None);
+    }
+}
@@ -1,10 +1,11 @@
-use crate::constants::JS_TAG;
+use crate::constants::{BIN_TAG, JS_TAG, LIB_TAG, MANIFEST, PRIVATE_TAG, TYPESCRIPT_TAG};
 use crate::package_manifest::PackageManifest;
-use ring_traits::{Project, Tagged};
+use ring_traits::{Dependency, DependencyKind, Project, Tagged};
 use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
 use semver::Version;
+use std::collections::BTreeMap;
 use std::rc::Rc;
-use crate::PackageManager;
+use crate::{parse_npm_alias, parse_npm_range, PackageManager};
 
 #[derive(Debug)]
 pub struct JsProject {
@@ -25,6 +26,26 @@ impl JsProject {
     pub fn package_manager(&self) -> &PackageManager {
         &self.package_manager
     }
+
+    /// Returns runnable scripts, by name
+    pub fn scripts(&self) -> &BTreeMap<String, String> {
+        &self.manifest.scripts
+    }
+
+    /// Returns executables provided by package, by command name
+    pub fn binaries(&self) -> BTreeMap<String, String> {
+        self.manifest.binaries()
+    }
+
+    /// Returns `true` if package is not meant to be published
+    pub fn is_private(&self) -> bool {
+        self.manifest.private
+    }
+
+    /// Returns `true` if package exposes entry points to be imported by other packages
+    pub fn is_library(&self) -> bool {
+        self.manifest.is_library()
+    }
 }
 
 impl Project for JsProject {
@@ -39,10 +60,132 @@ impl Project for JsProject {
     fn version(&self) -> Option<&Version> {
         self.manifest.version.as_ref()
     }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        let groups = [
+            (&self.manifest.dependencies, DependencyKind::Runtime),
+            (&self.manifest.dev_dependencies, DependencyKind::Dev),
+            (&self.manifest.peer_dependencies, DependencyKind::Peer),
+            (&self.manifest.optional_dependencies, DependencyKind::Optional),
+        ];
+
+        groups.into_iter()
+            .flat_map(|(deps, kind)| deps.iter()
+                .map(move |(name, req)| {
+                    // Aliases require another package, installed under their name
+                    let package = parse_npm_alias(req).map_or(name.as_str(), |(package, _)| package);
+
+                    Dependency::new(package.to_string(), req.clone(), kind)
+                        .with_version_reqs(parse_npm_range(req).unwrap_or_default())
+                })
+            )
+            .collect()
+    }
+
+    fn manifest_path(&self) -> Option<NormalizedPathBuf> {
+        Some(self.root.join(MANIFEST))
+    }
+
+    fn details(&self) -> Vec<(String, String)> {
+        let mut details = vec![("package manager".to_string(), self.package_manager.to_string())];
+
+        if let Some(license) = &self.manifest.license {
+            details.push(("license".to_string(), license.clone()));
+        }
+
+        if let Some(repository) = &self.manifest.repository {
+            details.push(("repository".to_string(), repository.url().to_string()));
+        }
+
+        for (engine, requirement) in &self.manifest.engines {
+            details.push(("engines".to_string(), format!("{engine} {requirement}")));
+        }
+
+        for (command, path) in self.binaries() {
+            details.push(("bin".to_string(), format!("{command}: {path}")));
+        }
+
+        for (name, script) in self.scripts() {
+            details.push(("scripts".to_string(), format!("{name}: {script}")));
+        }
+
+        details
+    }
 }
 
 impl Tagged for JsProject {
-    fn tags(&self) -> &[&'static Tag] {
-        &[&JS_TAG]
+    fn tags(&self) -> Vec<Tag> {
+        let mut tags = vec![JS_TAG];
+
+        if self.manifest.dependencies.contains_key("typescript") || self.manifest.dev_dependencies.contains_key("typescript") {
+            tags.push(TYPESCRIPT_TAG);
+        }
+
+        if self.is_library() {
+            tags.push(LIB_TAG);
+        }
+
+        if self.manifest.bin.is_some() {
+            tags.push(BIN_TAG);
+        }
+
+        if self.is_private() {
+            tags.push(PRIVATE_TAG);
+        }
+
+        tags
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use ring_utils::Normalize;
+    use crate::PackageBin;
+    use super::*;
+
+    #[test]
+    fn it_should_tag_typescript_projects() {
+        let manifest = PackageManifest {
+            name: "app".to_string(),
+            dev_dependencies: BTreeMap::from([("typescript".to_string(), "^5.4.0".to_string())]),
+            ..Default::default()
+        };
+        let project = JsProject::new(Path::new("/repo/app").normalize(), Rc::new(manifest), PackageManager::NPM);
+
+        assert_eq!(project.tags(), [JS_TAG, TYPESCRIPT_TAG]);
+    }
+
+    #[test]
+    fn it_should_tag_packages_by_kind() {
+        let manifest = PackageManifest {
+            name: "cli".to_string(),
+            private: true,
+            main: Some("./dist/index.js".to_string()),
+            bin: Some(PackageBin::Single("./bin/cli.js".to_string())),
+            ..Default::default()
+        };
+        let project = JsProject::new(Path::new("/repo/cli").normalize(), Rc::new(manifest), PackageManager::NPM);
+
+        assert_eq!(project.tags(), [JS_TAG, LIB_TAG, BIN_TAG, PRIVATE_TAG]);
+        assert_eq!(project.binaries().keys().collect::<Vec<_>>(), ["cli"]);
+    }
+
+    #[test]
+    fn it_should_require_aliased_packages() {
+        let manifest = PackageManifest {
+            name: "app".to_string(),
+            dependencies: BTreeMap::from([
+                ("string-width-cjs".to_string(), "npm:string-width@^4.2.0".to_string()),
+                ("ui".to_string(), "npm:@acme/ui".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let project = JsProject::new(Path::new("/repo/app").normalize(), Rc::new(manifest), PackageManager::NPM);
+        let dependencies = project.dependencies();
+
+        assert_eq!(dependencies.iter().map(|dep| dep.name()).collect::<Vec<_>>(), ["string-width", "@acme/ui"]);
+        assert!(dependencies.iter().all(|dep| dep.accepts("4.2.3")));
+    }
+}
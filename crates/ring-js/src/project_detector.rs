@@ -1,6 +1,6 @@
 use crate::constants::MANIFEST;
 use crate::{JsProject, PackageManifest};
-use ring_files::ManifestLoader;
+use ring_files::{FileSystem, ManifestLoader, RealFileSystem};
 use ring_traits::{Detect, DetectAs, Project, Tagged, detect_as, detect_from};
 use ring_utils::OptionalResult::{self, Found};
 use ring_utils::{NormalizedPath, PathTree};
@@ -14,21 +14,27 @@ pub struct JsProjectDetector {
     cache: RefCell<PathTree<Rc<JsProject>>>,
     lockfile_detector: JsLockfileDetector,
     package_loader: ManifestLoader<PackageManifest>,
+    fs: Rc<dyn FileSystem>,
 }
 
 impl JsProjectDetector {
-    pub fn new() -> JsProjectDetector {
+    pub fn new(fs: Rc<dyn FileSystem>) -> JsProjectDetector {
         JsProjectDetector {
             cache: RefCell::new(PathTree::new()),
-            lockfile_detector: JsLockfileDetector::new(),
-            package_loader: ManifestLoader::new(MANIFEST),
+            lockfile_detector: JsLockfileDetector::new(fs.clone()),
+            package_loader: ManifestLoader::new(MANIFEST, fs.clone()),
+            fs,
         }
     }
+
+    pub fn fs(&self) -> &Rc<dyn FileSystem> {
+        &self.fs
+    }
 }
 
 impl Default for JsProjectDetector {
     fn default() -> Self {
-        JsProjectDetector::new()
+        JsProjectDetector::new(Rc::new(RealFileSystem))
     }
 }
 
@@ -36,7 +42,7 @@ impl Detect for JsProjectDetector {
     type Item = Rc<JsProject>;
 
     fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
-        let path = if path.is_file() { path.parent().unwrap() } else { path };
+        let path = if self.fs.is_file(path) { path.parent().unwrap() } else { path };
         
         if let Some(project) = self.cache.borrow().get(path) {
             debug!("Found js project {} at {} (cached)", project.name(), path.display());
@@ -1,10 +1,10 @@
 use crate::constants::JS_TAG;
-use crate::{JsProject, JsProjectDetector, PackageManager};
-use ring_files::PatternIterator;
-use ring_traits::{Project, ProjectIterator, Scope, Tagged};
-use ring_utils::{NormalizedPath, Tag};
+use crate::{JsProject, JsProjectDetector, NpmLockfile, PackageManager, PnpmLockfile, YarnLockfile};
+use ring_files::{ManifestLoader, PatternIterator};
+use ring_traits::{Project, ProjectIterator, ResolvedPackage, Scope, Tagged};
+use ring_utils::{NormalizedPath, OptionalResult, Tag};
 use std::rc::Rc;
-use tracing::{debug, warn};
+use tracing::debug;
 
 #[derive(Debug)]
 pub struct JsScope {
@@ -34,23 +34,44 @@ impl Scope for JsScope {
         self.root_project.root()
     }
 
-    fn projects(&self) -> Box<ProjectIterator> {
+    fn root_project(&self) -> OptionalResult<Rc<dyn Project>> {
+        OptionalResult::Found(self.root_project.clone())
+    }
+
+    fn projects(&self) -> Box<ProjectIterator<'_>> {
         let projects = self.root_project.manifest().workspaces.iter()
             .resolve(self.root())
             .inspect(|pattern| debug!("Search js project matching {}", pattern.display()))
-            .glob_search()
-            .filter_map(|result| result
-                .inspect_err(|err| warn!("Error while loading scope project {:#}", err))
-                .ok()
-            )
-            .detect_at(self.project_detector.clone());
+            .glob_search(self.project_detector.fs().clone())
+            .try_detect_at(self.project_detector.clone());
 
         Box::new(projects)
     }
+
+    fn package_manager_name(&self) -> Option<&str> {
+        Some(self.package_manager().name())
+    }
+
+    fn resolved_packages(&self) -> OptionalResult<Vec<ResolvedPackage>> {
+        let lockfile = self.package_manager().lockfile();
+        let fs = self.project_detector.fs().clone();
+
+        match self.package_manager() {
+            PackageManager::NPM => ManifestLoader::<NpmLockfile>::new(lockfile, fs)
+                .load(self.root())
+                .map(|lockfile| lockfile.resolved_packages()),
+            PackageManager::PNPM => ManifestLoader::<PnpmLockfile>::new(lockfile, fs)
+                .load(self.root())
+                .map(|lockfile| lockfile.resolved_packages()),
+            PackageManager::Yarn => ManifestLoader::<YarnLockfile>::new(lockfile, fs)
+                .load(self.root())
+                .map(|lockfile| lockfile.resolved_packages()),
+        }
+    }
 }
 
 impl Tagged for JsScope {
-    fn tags(&self) -> &[&'static Tag] {
-        &[&JS_TAG]
+    fn tags(&self) -> Vec<Tag> {
+        vec![JS_TAG]
     }
 }
@@ -25,7 +25,7 @@ impl Detect for JsScopeDetector {
     type Item = Rc<JsScope>;
 
     fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
-        let path = if path.is_file() { path.parent().unwrap() } else { path };
+        let path = if self.project_detector.fs().is_file(path) { path.parent().unwrap() } else { path };
 
         if let Some(scope) = self.cache.borrow().get(path) {
             debug!("Found js scope at {} (cached)", path.display());
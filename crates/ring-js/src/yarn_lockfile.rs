@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::Deserialize;
+use serde_yaml::Value;
+use ring_traits::{Manifest, PackageRef, ResolvedPackage};
+use ring_utils::ManifestError;
+use crate::parse_errors::yaml_error;
+
+const BERRY_METADATA: &str = "__metadata";
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct BerryEntry {
+    version: String,
+    #[serde(default)]
+    resolution: Option<String>,
+    #[serde(default)]
+    checksum: Option<String>,
+    #[serde(default)]
+    link_type: Option<String>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct YarnLockfileEntry {
+    /// Descriptors (`name@range`) resolved by this entry
+    pub descriptors: Vec<String>,
+    pub version: String,
+    pub resolved: Option<String>,
+    pub checksum: Option<String>,
+    /// Dependencies, as descriptors
+    pub dependencies: Vec<String>,
+    /// Workspaces and portals are linked instead of installed
+    pub link: bool,
+}
+
+/// Content of a `yarn.lock` file, either classic (v1) or berry (v2+)
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct YarnLockfile {
+    pub entries: Vec<YarnLockfileEntry>,
+}
+
+impl YarnLockfile {
+    fn parse_berry(content: &str) -> ring_utils::Result<YarnLockfile> {
+        let mut document: BTreeMap<String, Value> = serde_yaml::from_str(content)
+            .map_err(|err| yaml_error(&err))?;
+        document.remove(BERRY_METADATA);
+
+        let entries = document.into_iter()
+            .map(|(key, value)| {
+                let entry: BerryEntry = serde_yaml::from_value(value)
+                    .map_err(|err| yaml_error(&err).with_key(key.clone()))?;
+
+                let dependencies = entry.dependencies.iter()
+                    .chain(&entry.optional_dependencies)
+                    .map(|(name, range)| if range.contains(':') {
+                        format!("{name}@{range}")
+                    } else {
+                        format!("{name}@npm:{range}")
+                    })
+                    .collect();
+
+                Ok(YarnLockfileEntry {
+                    descriptors: key.split(", ").map(str::to_string).collect(),
+                    version: entry.version,
+                    resolved: entry.resolution,
+                    checksum: entry.checksum,
+                    dependencies,
+                    link: entry.link_type.as_deref() == Some("soft"),
+                })
+            })
+            .collect::<ring_utils::Result<_>>()?;
+
+        Ok(YarnLockfile { entries })
+    }
+
+    fn parse_classic(content: &str) -> ring_utils::Result<YarnLockfile> {
+        let mut entries = Vec::new();
+        let mut current: Option<YarnLockfileEntry> = None;
+        let mut section: Option<String> = None;
+
+        for (idx, line) in content.lines().enumerate() {
+            let text = line.trim();
+
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let indent = line.len() - line.trim_start().len();
+            let error = || ManifestError::new("unexpected content").with_location(idx + 1, indent + 1);
+
+            match indent {
+                0 => {
+                    let descriptors = text.strip_suffix(':').ok_or_else(error)?;
+
+                    entries.extend(current.take());
+                    current = Some(YarnLockfileEntry {
+                        descriptors: descriptors.split(", ").map(|d| unquote(d).to_string()).collect(),
+                        ..Default::default()
+                    });
+                    section = None;
+                }
+                2 => {
+                    let entry = current.as_mut().ok_or_else(error)?;
+
+                    if let Some(name) = text.strip_suffix(':') {
+                        section = Some(unquote(name).to_string());
+                        continue;
+                    }
+
+                    section = None;
+
+                    let (key, value) = split_pair(text).ok_or_else(error)?;
+
+                    match key {
+                        "version" => entry.version = value.to_string(),
+                        "resolved" => entry.resolved = Some(value.to_string()),
+                        "integrity" => entry.checksum = Some(value.to_string()),
+                        _ => {}
+                    }
+                }
+                4 => {
+                    let entry = current.as_mut().ok_or_else(error)?;
+                    let (name, range) = split_pair(text).ok_or_else(error)?;
+
+                    if let Some("dependencies" | "optionalDependencies") = section.as_deref() {
+                        entry.dependencies.push(format!("{name}@{range}"));
+                    }
+                }
+                _ => return Err(error().into()),
+            }
+        }
+
+        entries.extend(current);
+
+        Ok(YarnLockfile { entries })
+    }
+
+    /// Returns every package installed by yarn
+    pub fn resolved_packages(&self) -> Vec<ResolvedPackage> {
+        let mut versions: HashMap<&str, (&str, &str)> = HashMap::new();
+
+        for entry in &self.entries {
+            for descriptor in &entry.descriptors {
+                versions.insert(descriptor, (entry_name(entry, descriptor), &entry.version));
+            }
+        }
+
+        self.entries.iter()
+            .filter(|entry| !entry.link)
+            .filter_map(|entry| {
+                let name = entry_name(entry, entry.descriptors.first()?);
+
+                let dependencies = entry.dependencies.iter()
+                    .filter_map(|descriptor| versions.get(descriptor.as_str()))
+                    .map(|(name, version)| PackageRef::new(name.to_string(), Some(version.to_string())))
+                    .collect();
+
+                Some(ResolvedPackage {
+                    name: name.to_string(),
+                    version: entry.version.clone(),
+                    source: entry.resolved.clone(),
+                    checksum: entry.checksum.clone(),
+                    dependencies,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Name of the package installed by an entry, taking aliases into account
+fn entry_name<'a>(entry: &'a YarnLockfileEntry, descriptor: &'a str) -> &'a str {
+    // Berry resolutions always reference the real package
+    let reference = entry.resolved.as_deref()
+        .filter(|res| split_descriptor(res).is_some_and(|(_, range)| range.starts_with("npm:")))
+        .unwrap_or(descriptor);
+
+    let Some((name, range)) = split_descriptor(reference) else {
+        return reference;
+    };
+
+    // Aliases are written like `alias@npm:name@^1.0.0`
+    range.strip_prefix("npm:")
+        .and_then(split_descriptor)
+        .map_or(name, |(name, _)| name)
+}
+
+/// Splits a descriptor like `@scope/name@^1.0.0` into its name and range
+fn split_descriptor(descriptor: &str) -> Option<(&str, &str)> {
+    let idx = descriptor.get(1..)?.find('@')? + 1;
+    Some((&descriptor[..idx], &descriptor[idx + 1..]))
+}
+
+/// Splits a classic key/value line, like `"@scope/name" "^1.0.0"`
+fn split_pair(text: &str) -> Option<(&str, &str)> {
+    let (key, value) = if let Some(rest) = text.strip_prefix('"') {
+        let end = rest.find('"')?;
+        (&rest[..end], &rest[end + 1..])
+    } else {
+        text.split_once(' ')?
+    };
+
+    Some((key, unquote(value.trim())))
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+impl Manifest for YarnLockfile {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        let is_berry = content.lines().any(|line| line.starts_with(BERRY_METADATA));
+
+        if is_berry {
+            YarnLockfile::parse_berry(content)
+        } else if content.trim().is_empty() || content.contains("# yarn lockfile v1") {
+            YarnLockfile::parse_classic(content)
+        } else {
+            Err(ManifestError::new("unsupported yarn lockfile format").into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_packages_from_classic_lockfile() {
+        let lockfile = YarnLockfile::from_str(r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.10.4":
+  version "7.12.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.12.13.tgz#dcfc826b"
+  integrity sha512-code-frame
+  dependencies:
+    "@babel/highlight" "^7.12.13"
+
+"@babel/highlight@^7.12.13":
+  version "7.13.10"
+  resolved "https://registry.yarnpkg.com/@babel/highlight/-/highlight-7.13.10.tgz#a8b2a66f"
+  integrity sha512-highlight
+  dependencies:
+    js-tokens "^4.0.0"
+
+js-tokens@^4.0.0:
+  version "4.0.0"
+"#).unwrap();
+
+        assert_eq!(lockfile.entries.len(), 3);
+        assert_eq!(lockfile.resolved_packages(), vec![
+            ResolvedPackage {
+                name: "@babel/code-frame".to_string(),
+                version: "7.12.13".to_string(),
+                source: Some("https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.12.13.tgz#dcfc826b".to_string()),
+                checksum: Some("sha512-code-frame".to_string()),
+                dependencies: vec![PackageRef::new("@babel/highlight".to_string(), Some("7.13.10".to_string()))],
+            },
+            ResolvedPackage {
+                name: "@babel/highlight".to_string(),
+                version: "7.13.10".to_string(),
+                source: Some("https://registry.yarnpkg.com/@babel/highlight/-/highlight-7.13.10.tgz#a8b2a66f".to_string()),
+                checksum: Some("sha512-highlight".to_string()),
+                dependencies: vec![PackageRef::new("js-tokens".to_string(), Some("4.0.0".to_string()))],
+            },
+            ResolvedPackage {
+                name: "js-tokens".to_string(),
+                version: "4.0.0".to_string(),
+                source: None,
+                checksum: None,
+                dependencies: vec![],
+            },
+        ]);
+    }
+
+    #[test]
+    fn it_should_list_packages_from_berry_lockfile() {
+        let lockfile = YarnLockfile::from_str(r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"js-tokens@npm:^3.0.0 || ^4.0.0":
+  version: 4.0.0
+  resolution: "js-tokens@npm:4.0.0"
+  checksum: 10c0/js-tokens
+  languageName: node
+  linkType: hard
+
+"loose-envify@npm:^1.1.0":
+  version: 1.4.0
+  resolution: "loose-envify@npm:1.4.0"
+  dependencies:
+    js-tokens: "npm:^3.0.0 || ^4.0.0"
+  checksum: 10c0/loose-envify
+  languageName: node
+  linkType: hard
+
+"ring@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "ring@workspace:."
+  dependencies:
+    loose-envify: "npm:^1.1.0"
+  languageName: unknown
+  linkType: soft
+
+"string-width-cjs@npm:string-width@^4.2.0":
+  version: 4.2.3
+  resolution: "string-width@npm:4.2.3"
+  checksum: 10c0/string-width
+  languageName: node
+  linkType: hard
+"#).unwrap();
+
+        assert_eq!(lockfile.entries.len(), 4);
+        assert_eq!(lockfile.resolved_packages(), vec![
+            ResolvedPackage {
+                name: "js-tokens".to_string(),
+                version: "4.0.0".to_string(),
+                source: Some("js-tokens@npm:4.0.0".to_string()),
+                checksum: Some("10c0/js-tokens".to_string()),
+                dependencies: vec![],
+            },
+            ResolvedPackage {
+                name: "loose-envify".to_string(),
+                version: "1.4.0".to_string(),
+                source: Some("loose-envify@npm:1.4.0".to_string()),
+                checksum: Some("10c0/loose-envify".to_string()),
+                dependencies: vec![PackageRef::new("js-tokens".to_string(), Some("4.0.0".to_string()))],
+            },
+            ResolvedPackage {
+                name: "string-width".to_string(),
+                version: "4.2.3".to_string(),
+                source: Some("string-width@npm:4.2.3".to_string()),
+                checksum: Some("10c0/string-width".to_string()),
+                dependencies: vec![],
+            },
+        ]);
+    }
+
+    #[test]
+    fn it_should_report_line_of_invalid_content() {
+        let result = YarnLockfile::from_str("# yarn lockfile v1\n\njs-tokens@^4.0.0:\n      version \"4.0.0\"\n");
+
+        let error = result.unwrap_err();
+        let error = error.manifest_error().unwrap();
+
+        assert_eq!(error.message(), "unexpected content");
+        assert_eq!(error.line(), Some(4));
+    }
+}
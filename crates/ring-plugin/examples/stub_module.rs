@@ -0,0 +1,121 @@
+//! Minimal plugin, detecting directories containing a `stub.json` file like:
+//!
+//! ```json
+//! { "name": "app", "version": "1.0.0", "members": ["packages/a", "packages/b"] }
+//! ```
+//!
+//! Directories with members are also scopes. Build it with `cargo build --example stub_module`,
+//! then declare it in `ring.toml`:
+//!
+//! ```toml
+//! [plugins.stub]
+//! command = "/path/to/target/debug/examples/stub_module"
+//! ```
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use semver::Version;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use ring_plugin::protocol::{
+    DetectResult, InitializeResult, PathParams, ProjectData, Request, Response, ResponseError,
+    ScopeData, TagData, DETECT_AT, DETECT_FROM, INITIALIZE, JSONRPC_VERSION, PROJECTS,
+};
+
+const MARKER: &str = "stub.json";
+
+#[derive(Deserialize)]
+struct StubManifest {
+    name: String,
+    #[serde(default)]
+    version: Option<Version>,
+    #[serde(default)]
+    members: Vec<PathBuf>,
+}
+
+fn load(root: &Path) -> Option<StubManifest> {
+    let content = fs::read_to_string(root.join(MARKER)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn project(root: &Path, manifest: &StubManifest) -> ProjectData {
+    ProjectData {
+        root: root.to_path_buf(),
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        tags: vec![],
+        dependencies: vec![],
+    }
+}
+
+fn detect_at(root: &Path) -> DetectResult {
+    match load(root) {
+        Some(manifest) => DetectResult {
+            scope: (!manifest.members.is_empty()).then(|| ScopeData { root: root.to_path_buf(), tags: vec![] }),
+            project: Some(project(root, &manifest)),
+        },
+        None => DetectResult::default(),
+    }
+}
+
+fn handle(request: &Request) -> Result<Value, String> {
+    let path = || serde_json::from_value::<PathParams>(request.params.clone())
+        .map(|params| params.path)
+        .map_err(|err| err.to_string());
+
+    let result = match request.method.as_str() {
+        INITIALIZE => json!(InitializeResult {
+            tag: Some(TagData { label: "stub".to_string(), color: Some("magenta".to_string()) }),
+        }),
+        DETECT_AT => json!(detect_at(&path()?)),
+        DETECT_FROM => {
+            let path = path()?;
+            let mut result = DetectResult::default();
+
+            for ancestor in path.ancestors() {
+                let detected = detect_at(ancestor);
+                result.project = result.project.or(detected.project);
+                result.scope = result.scope.or(detected.scope);
+            }
+
+            json!(result)
+        }
+        PROJECTS => {
+            let root = path()?;
+            let members = load(&root).map(|manifest| manifest.members).unwrap_or_default();
+
+            let projects = members.iter()
+                .map(|member| root.join(member))
+                .filter_map(|member| load(&member).map(|manifest| project(&member, &manifest)))
+                .collect::<Vec<_>>();
+
+            json!(projects)
+        }
+        method => return Err(format!("Unknown method {method}")),
+    };
+
+    Ok(result)
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    for line in io::stdin().lock().lines() {
+        let request: Request = serde_json::from_str(&line?)?;
+
+        let response = match handle(&request) {
+            Ok(result) => Response { jsonrpc: JSONRPC_VERSION.to_string(), id: request.id, result: Some(result), error: None },
+            Err(message) => Response {
+                jsonrpc: JSONRPC_VERSION.to_string(),
+                id: request.id,
+                result: None,
+                error: Some(ResponseError { code: -32601, message }),
+            },
+        };
+
+        writeln!(stdout, "{}", serde_json::to_string(&response)?)?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
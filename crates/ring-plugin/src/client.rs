@@ -0,0 +1,273 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{debug, trace};
+use ring_utils::{Context, Error};
+use crate::protocol::{Request, Response, JSONRPC_VERSION};
+
+/// Time given to a plugin to exit once its input is closed, before it gets killed
+const STOP_TIMEOUT: Duration = Duration::from_millis(500);
+
+struct Transport {
+    reader: Box<dyn BufRead>,
+    writer: Box<dyn Write>,
+    child: Option<Child>,
+}
+
+/// JSON-RPC client talking to a plugin through its stdio.
+///
+/// The plugin process is spawned on first request, and stopped when the client is dropped.
+pub struct PluginClient {
+    name: String,
+    program: PathBuf,
+    args: Vec<String>,
+    transport: RefCell<Option<Transport>>,
+    next_id: Cell<u64>,
+}
+
+impl PluginClient {
+    pub fn new(name: String, program: PathBuf, args: Vec<String>) -> PluginClient {
+        PluginClient {
+            name,
+            program,
+            args,
+            transport: RefCell::new(None),
+            next_id: Cell::new(1),
+        }
+    }
+
+    /// Builds a client using given streams instead of spawning the plugin
+    pub fn with_streams(name: String, reader: impl BufRead + 'static, writer: impl Write + 'static) -> PluginClient {
+        PluginClient {
+            name,
+            program: PathBuf::new(),
+            args: Vec::new(),
+            transport: RefCell::new(Some(Transport {
+                reader: Box::new(reader),
+                writer: Box::new(writer),
+                child: None,
+            })),
+            next_id: Cell::new(1),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn spawn(&self) -> ring_utils::Result<Transport> {
+        debug!("Starting plugin {} ({})", self.name, self.program.display());
+
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| Error::io(&self.program, err))
+            .with_context(|| format!("Unable to start plugin {}", self.name))?;
+
+        let writer = child.stdin.take().expect("plugin stdin should be piped");
+        let reader = child.stdout.take().expect("plugin stdout should be piped");
+
+        Ok(Transport {
+            reader: Box::new(BufReader::new(reader)),
+            writer: Box::new(writer),
+            child: Some(child),
+        })
+    }
+
+    /// Sends a request to the plugin, and waits for its response
+    pub fn request<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> ring_utils::Result<R> {
+        let mut transport = self.transport.borrow_mut();
+
+        if transport.is_none() {
+            *transport = Some(self.spawn()?);
+        }
+
+        self.send(transport.as_mut().unwrap(), method, params)
+            .map_err(|message| Error::command(format!("plugin {}", self.name), format!("{method} request {message}")))
+    }
+
+    /// Exchanges messages with the plugin, failures are described by the returned message
+    fn send<P: Serialize, R: DeserializeOwned>(&self, transport: &mut Transport, method: &str, params: P) -> Result<R, String> {
+        let id = self.next_id.replace(self.next_id.get() + 1);
+        let request = serde_json::to_string(&Request {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            method: method.to_string(),
+            params: serde_json::to_value(params).map_err(|err| format!("has invalid params: {err}"))?,
+        }).map_err(|err| format!("has invalid params: {err}"))?;
+
+        trace!("Sending to plugin {}: {}", self.name, request);
+        writeln!(transport.writer, "{request}")
+            .and_then(|_| transport.writer.flush())
+            .map_err(|err| format!("could not be sent: {err}"))?;
+
+        loop {
+            let mut line = String::new();
+
+            let read = transport.reader.read_line(&mut line)
+                .map_err(|err| format!("response could not be read: {err}"))?;
+
+            if read == 0 {
+                return Err("got no response, plugin closed its output".to_string());
+            }
+
+            trace!("Received from plugin {}: {}", self.name, line.trim_end());
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response: Response = serde_json::from_str(&line)
+                .map_err(|err| format!("got an invalid response: {err}"))?;
+
+            // Ignore responses to previous (failed) requests
+            if response.id != id {
+                continue;
+            }
+
+            if let Some(error) = response.error {
+                return Err(format!("returned an error: {} (code {})", error.message, error.code));
+            }
+
+            return serde_json::from_value(response.result.unwrap_or_default())
+                .map_err(|err| format!("got an invalid result: {err}"));
+        }
+    }
+}
+
+impl Debug for PluginClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginClient")
+            .field("name", &self.name)
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl Drop for PluginClient {
+    fn drop(&mut self) {
+        if let Some(transport) = self.transport.get_mut().take() {
+            // Closing stdin asks the plugin to stop
+            drop(transport.writer);
+
+            if let Some(mut child) = transport.child {
+                debug!("Stopping plugin {}", self.name);
+                let deadline = Instant::now() + STOP_TIMEOUT;
+
+                while matches!(child.try_wait(), Ok(None)) && Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(10));
+                }
+
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::Cursor;
+    use std::rc::Rc;
+    use serde_json::{json, Value};
+    use ring_utils::ErrorKind;
+    use super::*;
+
+    /// Writer keeping written data in a shared buffer
+    #[derive(Clone, Default)]
+    pub(crate) struct SharedBuffer(pub Rc<RefCell<Vec<u8>>>);
+
+    impl SharedBuffer {
+        pub(crate) fn requests(&self) -> Vec<Request> {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Builds a client receiving given responses, in order
+    pub(crate) fn client_with_responses(responses: &[Value]) -> (PluginClient, SharedBuffer) {
+        let output = responses.iter()
+            .map(|response| format!("{response}\n"))
+            .collect::<String>();
+
+        let buffer = SharedBuffer::default();
+        let client = PluginClient::with_streams("test".to_string(), Cursor::new(output), buffer.clone());
+
+        (client, buffer)
+    }
+
+    #[test]
+    fn it_should_send_requests_and_parse_results() {
+        let (client, buffer) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "answer": 42 } }),
+        ]);
+
+        let result: Value = client.request("question", json!({ "about": "life" })).unwrap();
+
+        assert_eq!(result, json!({ "answer": 42 }));
+        assert_eq!(buffer.requests(), vec![Request {
+            jsonrpc: "2.0".to_string(),
+            id: 1,
+            method: "question".to_string(),
+            params: json!({ "about": "life" }),
+        }]);
+    }
+
+    #[test]
+    fn it_should_skip_responses_to_other_requests() {
+        let (client, _) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 0, "result": "old" }),
+            json!({ "jsonrpc": "2.0", "id": 1, "result": "new" }),
+        ]);
+
+        let result: String = client.request("test", ()).unwrap();
+
+        assert_eq!(result, "new");
+    }
+
+    #[test]
+    fn it_should_return_plugin_errors() {
+        let (client, _) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32601, "message": "Method not found" } }),
+        ]);
+
+        let result = client.request::<_, Value>("unknown", ());
+
+        let error = result.unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Command);
+        assert_eq!(
+            error.to_string(),
+            "plugin test failed: unknown request returned an error: Method not found (code -32601)"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_plugin_closes_its_output() {
+        let (client, _) = client_with_responses(&[]);
+
+        assert!(client.request::<_, Value>("test", ()).is_err());
+    }
+}
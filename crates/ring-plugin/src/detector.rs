@@ -0,0 +1,192 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use tracing::{debug, info};
+use ring_traits::{DetectAs, Project, Scope, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, OptionalResult};
+use crate::{PluginProject, PluginScope};
+use crate::plugin::Plugin;
+use crate::protocol::{DetectResult, PathParams, DETECT_AT, DETECT_FROM};
+
+#[derive(Clone, Debug, Default)]
+struct Detected {
+    project: Option<Rc<PluginProject>>,
+    scope: Option<Rc<PluginScope>>,
+}
+
+/// Detects projects and scopes by asking the plugin
+#[derive(Debug)]
+pub struct PluginDetector {
+    plugin: Rc<Plugin>,
+    cache: RefCell<BTreeMap<NormalizedPathBuf, Detected>>,
+}
+
+impl PluginDetector {
+    pub(crate) fn new(plugin: Rc<Plugin>) -> PluginDetector {
+        PluginDetector {
+            plugin,
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    fn request(&self, method: &str, path: &NormalizedPath) -> ring_utils::Result<Detected> {
+        let params = PathParams { path: path.as_path().to_path_buf() };
+        let result: Option<DetectResult> = self.plugin.client().request(method, params)?;
+        let result = result.unwrap_or_default();
+
+        Ok(Detected {
+            project: result.project
+                .map(|data| self.plugin.project(data).map(Rc::new))
+                .transpose()?,
+            scope: result.scope
+                .map(|data| self.plugin.scope(data).map(Rc::new))
+                .transpose()?,
+        })
+    }
+
+    fn detect_at(&self, path: &NormalizedPath) -> ring_utils::Result<Detected> {
+        if let Some(detected) = self.cache.borrow().get(path) {
+            debug!("Found {} plugin items at {} (cached)", self.plugin.client().name(), path.display());
+            return Ok(detected.clone());
+        }
+
+        let detected = self.request(DETECT_AT, path)?;
+        self.cache.borrow_mut().insert(path.to_path_buf(), detected.clone());
+
+        Ok(detected)
+    }
+
+    fn detect_from(&self, path: &NormalizedPath) -> ring_utils::Result<Detected> {
+        info!("Searching {} plugin items from {}", self.plugin.client().name(), path.display());
+        self.request(DETECT_FROM, path)
+    }
+}
+
+fn found<T>(result: ring_utils::Result<Option<T>>) -> OptionalResult<T> {
+    match result {
+        Ok(Some(item)) => OptionalResult::Found(item),
+        Ok(None) => OptionalResult::Empty,
+        Err(err) => OptionalResult::Fail(err),
+    }
+}
+
+impl DetectAs<Rc<dyn Project>> for PluginDetector {
+    fn detect_at_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Project>> {
+        found(self.detect_at(path).map(|detected| detected.project.map(|prj| prj as Rc<dyn Project>)))
+    }
+
+    fn detect_from_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Project>> {
+        found(self.detect_from(path).map(|detected| detected.project.map(|prj| prj as Rc<dyn Project>)))
+    }
+}
+
+impl DetectAs<Rc<dyn Scope>> for PluginDetector {
+    fn detect_at_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Scope>> {
+        found(self.detect_at(path).map(|detected| detected.scope.map(|scp| scp as Rc<dyn Scope>)))
+    }
+
+    fn detect_from_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Scope>> {
+        found(self.detect_from(path).map(|detected| detected.scope.map(|scp| scp as Rc<dyn Scope>)))
+    }
+}
+
+impl DetectAs<Rc<dyn Tagged>> for PluginDetector {
+    fn detect_at_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Tagged>> {
+        found(self.detect_at(path).map(tagged))
+    }
+
+    fn detect_from_as(&self, path: &NormalizedPath) -> OptionalResult<Rc<dyn Tagged>> {
+        found(self.detect_from(path).map(tagged))
+    }
+}
+
+/// Projects are preferred over scopes
+fn tagged(detected: Detected) -> Option<Rc<dyn Tagged>> {
+    match detected {
+        Detected { project: Some(prj), .. } => Some(prj),
+        Detected { scope: Some(scp), .. } => Some(scp),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use serde_json::json;
+    use ring_utils::Normalize;
+    use crate::client::tests::client_with_responses;
+    use super::*;
+
+    #[test]
+    fn it_should_convert_plugin_results_and_cache_them() {
+        let (client, buffer) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "result": {
+                "project": {
+                    "root": "/repo/app",
+                    "name": "app",
+                    "version": "1.0.0",
+                    "tags": ["bin"],
+                    "dependencies": [{ "name": "lib", "requirement": "^1.0.0", "kind": "dev" }]
+                }
+            } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "result": { "tag": { "label": "bazel", "color": "green" } } }),
+        ]);
+
+        let detector = PluginDetector::new(Rc::new(Plugin::new(client)));
+        let path = Path::new("/repo/app").normalize();
+
+        let project: Rc<dyn Project> = detector.detect_at_as(&path).result_or_else(|| panic!("project not found")).unwrap();
+        assert_eq!(project.name(), "app");
+        assert_eq!(project.version().map(|v| v.to_string()), Some("1.0.0".to_string()));
+        assert_eq!(project.tags().iter().map(|tag| tag.label()).collect::<Vec<_>>(), ["bazel", "bin"]);
+        assert_eq!(project.dependencies()[0].kind(), ring_traits::DependencyKind::Dev);
+        assert!(project.dependencies()[0].accepts("1.2.0"));
+
+        let scope: OptionalResult<Rc<dyn Scope>> = detector.detect_at_as(&path);
+        assert!(scope.is_empty());
+
+        let methods = buffer.requests().into_iter().map(|req| req.method).collect::<Vec<_>>();
+        assert_eq!(methods, [DETECT_AT, "initialize"]);
+    }
+
+    #[test]
+    fn it_should_list_scope_projects_using_plugin() {
+        let (client, buffer) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "scope": { "root": "/repo" } } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "result": {} }),
+            json!({ "jsonrpc": "2.0", "id": 3, "result": [
+                { "root": "/repo/app", "name": "app" },
+                { "root": "/repo/lib", "name": "lib" }
+            ] }),
+        ]);
+
+        let detector = PluginDetector::new(Rc::new(Plugin::new(client)));
+
+        let scope: Rc<dyn Scope> = detector.detect_from_as(&Path::new("/repo/app/src").normalize())
+            .result_or_else(|| panic!("scope not found"))
+            .unwrap();
+        assert_eq!(scope.root(), Path::new("/repo"));
+        assert_eq!(scope.tags().iter().map(|tag| tag.label()).collect::<Vec<_>>(), ["test"]);
+
+        let projects = scope.projects().collect::<ring_utils::Result<Vec<_>>>().unwrap();
+        assert_eq!(projects.iter().map(|prj| prj.name()).collect::<Vec<_>>(), ["app", "lib"]);
+
+        let requests = buffer.requests();
+        assert_eq!(requests[0].method, DETECT_FROM);
+        assert_eq!(requests[0].params, json!({ "path": "/repo/app/src" }));
+        assert_eq!(requests[2].params, json!({ "path": "/repo" }));
+    }
+
+    #[test]
+    fn it_should_reject_relative_roots() {
+        let (client, _) = client_with_responses(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "project": { "root": "app", "name": "app" } } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "result": {} }),
+        ]);
+
+        let detector = PluginDetector::new(Rc::new(Plugin::new(client)));
+        let project: OptionalResult<Rc<dyn Project>> = detector.detect_at_as(&Path::new("/repo/app").normalize());
+
+        assert!(project.is_fail());
+    }
+}
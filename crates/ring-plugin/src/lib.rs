@@ -0,0 +1,151 @@
+mod client;
+mod detector;
+mod plugin;
+mod project;
+pub mod protocol;
+mod scope;
+
+pub use client::PluginClient;
+pub use detector::PluginDetector;
+pub use project::PluginProject;
+pub use scope::PluginScope;
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::rc::Rc;
+use tracing::debug;
+use ring_traits::{Module, ProjectDetector, ScopeDetector, TaggedDetector};
+use crate::plugin::Plugin;
+
+/// Prefix of plugin executables
+pub const PLUGIN_PREFIX: &str = "ring-module-";
+
+// Module
+#[derive(Debug)]
+pub struct PluginModule {
+    name: String,
+    detector: Rc<PluginDetector>,
+}
+
+impl PluginModule {
+    /// Builds a module backed by given plugin executable
+    pub fn new(name: String, program: PathBuf, args: Vec<String>) -> PluginModule {
+        debug!("Initializing {} plugin module", name);
+        PluginModule::with_client(PluginClient::new(name, program, args))
+    }
+
+    pub fn with_client(client: PluginClient) -> PluginModule {
+        PluginModule {
+            name: client.name().to_string(),
+            detector: Rc::new(PluginDetector::new(Rc::new(Plugin::new(client)))),
+        }
+    }
+}
+
+impl Module for PluginModule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn project_detectors(&self) -> Vec<Rc<ProjectDetector>> {
+        vec![
+            self.detector.clone()
+        ]
+    }
+
+    fn scope_detectors(&self) -> Vec<Rc<ScopeDetector>> {
+        vec![
+            self.detector.clone()
+        ]
+    }
+
+    fn tagged_detectors(&self) -> Vec<Rc<TaggedDetector>> {
+        vec![
+            self.detector.clone()
+        ]
+    }
+}
+
+/// Searches `ring-module-<name>` executables in `PATH`, returns them by name.
+///
+/// When many executables have the same name, the first one in `PATH` wins.
+pub fn find_plugins() -> BTreeMap<String, PathBuf> {
+    env::var_os("PATH")
+        .map(|path| find_plugins_in(&path))
+        .unwrap_or_default()
+}
+
+fn find_plugins_in(path: &OsStr) -> BTreeMap<String, PathBuf> {
+    let mut plugins = BTreeMap::new();
+
+    for dir in env::split_paths(path) {
+        let Ok(entries) = dir.read_dir() else { continue };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            let name = path.file_stem()
+                .and_then(OsStr::to_str)
+                .and_then(|stem| stem.strip_prefix(PLUGIN_PREFIX))
+                .filter(|name| !name.is_empty());
+
+            if let Some(name) = name {
+                if is_executable(&path) && !plugins.contains_key(name) {
+                    debug!("Found plugin {} at {}", name, path.display());
+                    plugins.insert(name.to_string(), path.clone());
+                }
+            }
+        }
+    }
+
+    plugins
+}
+
+#[cfg(unix)]
+fn is_executable(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &std::path::Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|ext| ext == "exe")
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use super::*;
+
+    #[test]
+    fn it_should_find_executable_plugins() {
+        let dir = env::temp_dir().join(format!("ring-plugin-test-{}", std::process::id()));
+        let first = dir.join("first");
+        let second = dir.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+
+        for (path, mode) in [
+            (first.join("ring-module-bazel"), 0o755),
+            (first.join("ring-module-notes"), 0o644),
+            (second.join("ring-module-bazel"), 0o755),
+            (second.join("ring-module-gradle"), 0o755),
+            (second.join("other-tool"), 0o755),
+        ] {
+            fs::write(&path, "").unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(mode)).unwrap();
+        }
+
+        let plugins = find_plugins_in(&env::join_paths([&first, &second]).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(plugins, BTreeMap::from([
+            ("bazel".to_string(), first.join("ring-module-bazel")),
+            ("gradle".to_string(), second.join("ring-module-gradle")),
+        ]));
+    }
+}
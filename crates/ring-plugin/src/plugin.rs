@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+use owo_colors::DynColors;
+use semver::VersionReq;
+use ring_traits::Dependency;
+use ring_utils::{Error, Normalize, NormalizedPathBuf, Tag};
+use crate::{PluginClient, PluginProject, PluginScope};
+use crate::protocol::{InitializeResult, ProjectData, ScopeData, INITIALIZE};
+
+/// Running plugin, converting its messages into projects and scopes
+#[derive(Debug)]
+pub(crate) struct Plugin {
+    client: PluginClient,
+    tag: RefCell<Option<Tag>>,
+}
+
+impl Plugin {
+    pub(crate) fn new(client: PluginClient) -> Plugin {
+        Plugin {
+            client,
+            tag: RefCell::new(None),
+        }
+    }
+
+    pub(crate) fn client(&self) -> &PluginClient {
+        &self.client
+    }
+
+    fn error(&self, message: String) -> Error {
+        Error::command(format!("plugin {}", self.client.name()), message)
+    }
+
+    /// Tag of this plugin, given by the plugin on initialization
+    fn tag(&self) -> ring_utils::Result<Tag> {
+        if let Some(tag) = &*self.tag.borrow() {
+            return Ok(tag.clone());
+        }
+
+        let result: InitializeResult = self.client.request(INITIALIZE, ())?;
+
+        let tag = match result.tag {
+            Some(data) => {
+                let color = data.color
+                    .map(|color| color.parse::<DynColors>()
+                        .map_err(|_| self.error(format!("returned invalid tag color {color}"))))
+                    .transpose()?;
+
+                Tag::owned(data.label, color)
+            }
+            None => Tag::owned(self.client.name().to_string(), None),
+        };
+
+        *self.tag.borrow_mut() = Some(tag.clone());
+
+        Ok(tag)
+    }
+
+    fn tags(&self, extra: &[String]) -> ring_utils::Result<Vec<Tag>> {
+        let mut tags = vec![self.tag()?];
+
+        for label in extra {
+            let tag = Tag::owned(label.clone(), None);
+
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        Ok(tags)
+    }
+
+    fn root(&self, root: &Path) -> ring_utils::Result<NormalizedPathBuf> {
+        if !root.is_absolute() {
+            return Err(self.error(format!("returned relative root {}", root.display())));
+        }
+
+        Ok(root.normalize())
+    }
+
+    pub(crate) fn project(&self, data: ProjectData) -> ring_utils::Result<PluginProject> {
+        let dependencies = data.dependencies.into_iter()
+            .map(|dep| {
+                let version_reqs = VersionReq::parse(&dep.requirement).into_iter().collect();
+
+                Dependency::new(dep.name, dep.requirement, dep.kind.into())
+                    .with_version_reqs(version_reqs)
+            })
+            .collect();
+
+        Ok(PluginProject::new(
+            self.root(&data.root)?,
+            data.name,
+            data.version,
+            self.tags(&data.tags)?,
+            dependencies,
+        ))
+    }
+
+    pub(crate) fn scope(self: &Rc<Self>, data: ScopeData) -> ring_utils::Result<PluginScope> {
+        Ok(PluginScope::new(self.root(&data.root)?, self.tags(&data.tags)?, self.clone()))
+    }
+}
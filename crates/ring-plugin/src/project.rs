@@ -0,0 +1,43 @@
+use semver::Version;
+use ring_traits::{Dependency, Project, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
+
+/// Project detected by a plugin
+#[derive(Debug)]
+pub struct PluginProject {
+    root: NormalizedPathBuf,
+    name: String,
+    version: Option<Version>,
+    tags: Vec<Tag>,
+    dependencies: Vec<Dependency>,
+}
+
+impl PluginProject {
+    pub fn new(root: NormalizedPathBuf, name: String, version: Option<Version>, tags: Vec<Tag>, dependencies: Vec<Dependency>) -> PluginProject {
+        PluginProject { root, name, version, tags, dependencies }
+    }
+}
+
+impl Project for PluginProject {
+    fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        self.dependencies.clone()
+    }
+}
+
+impl Tagged for PluginProject {
+    fn tags(&self) -> Vec<Tag> {
+        self.tags.clone()
+    }
+}
@@ -0,0 +1,132 @@
+//! Messages exchanged with plugins.
+//!
+//! Plugins read JSON-RPC 2.0 requests from their stdin, one per line, and write responses to
+//! their stdout, one per line. Supported methods are:
+//! - `initialize` (no params) => [`InitializeResult`]
+//! - `detect_at` ([`PathParams`]) => [`DetectResult`], project and/or scope rooted at given path
+//! - `detect_from` ([`PathParams`]) => [`DetectResult`], nearest project and/or scope from given path
+//! - `projects` ([`PathParams`]) => list of [`ProjectData`], projects of the scope rooted at given path
+use std::path::PathBuf;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ring_traits::DependencyKind;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+pub const INITIALIZE: &str = "initialize";
+pub const DETECT_AT: &str = "detect_at";
+pub const DETECT_FROM: &str = "detect_from";
+pub const PROJECTS: &str = "projects";
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct Request {
+    pub jsonrpc: String,
+    pub id: u64,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct Response {
+    pub jsonrpc: String,
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ResponseError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InitializeResult {
+    /// Tag added to every project and scope of the plugin (defaults to the plugin name)
+    #[serde(default)]
+    pub tag: Option<TagData>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct TagData {
+    pub label: String,
+    /// Color name, like `blue`, or hex code, like `#ff8000`
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct PathParams {
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DetectResult {
+    #[serde(default)]
+    pub project: Option<ProjectData>,
+    #[serde(default)]
+    pub scope: Option<ScopeData>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ProjectData {
+    /// Absolute path of project root
+    pub root: PathBuf,
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<Version>,
+    /// Additional tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub dependencies: Vec<DependencyData>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct DependencyData {
+    pub name: String,
+    #[serde(default = "any_requirement")]
+    pub requirement: String,
+    #[serde(default)]
+    pub kind: DependencyKindData,
+}
+
+fn any_requirement() -> String {
+    "*".to_string()
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyKindData {
+    #[default]
+    Runtime,
+    Dev,
+    Build,
+    Peer,
+    Optional,
+}
+
+impl From<DependencyKindData> for DependencyKind {
+    fn from(kind: DependencyKindData) -> Self {
+        match kind {
+            DependencyKindData::Runtime => DependencyKind::Runtime,
+            DependencyKindData::Dev => DependencyKind::Dev,
+            DependencyKindData::Build => DependencyKind::Build,
+            DependencyKindData::Peer => DependencyKind::Peer,
+            DependencyKindData::Optional => DependencyKind::Optional,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ScopeData {
+    /// Absolute path of scope root
+    pub root: PathBuf,
+    /// Additional tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,51 @@
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use ring_traits::{Project, ProjectIterator, Scope, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
+use crate::plugin::Plugin;
+use crate::protocol::{PathParams, ProjectData, PROJECTS};
+
+/// Scope detected by a plugin, its projects are listed by the plugin
+pub struct PluginScope {
+    root: NormalizedPathBuf,
+    tags: Vec<Tag>,
+    plugin: Rc<Plugin>,
+}
+
+impl PluginScope {
+    pub(crate) fn new(root: NormalizedPathBuf, tags: Vec<Tag>, plugin: Rc<Plugin>) -> PluginScope {
+        PluginScope { root, tags, plugin }
+    }
+}
+
+impl Scope for PluginScope {
+    fn root(&self) -> &NormalizedPath {
+        &self.root
+    }
+
+    fn projects(&self) -> Box<ProjectIterator<'_>> {
+        let params = PathParams { path: self.root.as_path().to_path_buf() };
+
+        match self.plugin.client().request::<_, Vec<ProjectData>>(PROJECTS, params) {
+            Ok(projects) => Box::new(projects.into_iter()
+                .map(|data| self.plugin.project(data).map(|prj| Rc::new(prj) as Rc<dyn Project>))),
+            Err(err) => Box::new(std::iter::once(Err(err))),
+        }
+    }
+}
+
+impl Tagged for PluginScope {
+    fn tags(&self) -> Vec<Tag> {
+        self.tags.clone()
+    }
+}
+
+impl Debug for PluginScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginScope")
+            .field("root", &self.root)
+            .field("tags", &self.tags)
+            .field("plugin", &self.plugin.client().name())
+            .finish()
+    }
+}
@@ -0,0 +1,38 @@
+//! Runs the `stub_module` example as a real plugin process. `cargo test` builds examples next to
+//! the test binaries, so the executable is looked up from the current one.
+
+use std::rc::Rc;
+use std::{env, fs};
+use ring_plugin::PluginModule;
+use ring_traits::{Module, Project, Scope};
+use ring_utils::Normalize;
+
+#[test]
+fn it_should_detect_projects_through_stub_module() {
+    let exe = env::current_exe().unwrap();
+    let program = exe.parent().and_then(|deps| deps.parent()).unwrap()
+        .join("examples")
+        .join(format!("stub_module{}", env::consts::EXE_SUFFIX));
+
+    let dir = env::temp_dir().join(format!("ring-plugin-stub-module-{}", std::process::id())).normalize();
+    fs::create_dir_all(dir.join("packages/a/src")).unwrap();
+    fs::write(dir.join("stub.json"), r#"{ "name": "root", "members": ["packages/a"] }"#).unwrap();
+    fs::write(dir.join("packages/a/stub.json"), r#"{ "name": "a", "version": "1.0.0" }"#).unwrap();
+
+    let module = PluginModule::new("stub".to_string(), program, vec![]);
+
+    let project: Rc<dyn Project> = module.project_detectors()[0].detect_from_as(&dir.join("packages/a/src"))
+        .result_or_else(|| panic!("project not found"))
+        .unwrap();
+    let scope: Rc<dyn Scope> = module.scope_detectors()[0].detect_at_as(&dir)
+        .result_or_else(|| panic!("scope not found"))
+        .unwrap();
+    let members = scope.projects().collect::<ring_utils::Result<Vec<_>>>().unwrap();
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(project.name(), "a");
+    assert_eq!(project.root(), dir.join("packages/a").as_path());
+    assert_eq!(project.tags().iter().map(|tag| tag.label()).collect::<Vec<_>>(), ["stub"]);
+    assert_eq!(members.iter().map(|prj| prj.name()).collect::<Vec<_>>(), ["a"]);
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use ring_traits::{Manifest, PackageRef, ResolvedPackage};
+use crate::parse_errors::toml_error;
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct CargoLockfilePackage {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub checksum: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Content of a `Cargo.lock` file
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct CargoLockfile {
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default, rename = "package")]
+    pub packages: Vec<CargoLockfilePackage>,
+}
+
+impl CargoLockfile {
+    /// Returns every package locked in the lockfile
+    pub fn resolved_packages(&self) -> Vec<ResolvedPackage> {
+        self.packages.iter()
+            .map(|pkg| ResolvedPackage {
+                name: pkg.name.clone(),
+                version: pkg.version.clone(),
+                source: pkg.source.clone(),
+                checksum: pkg.checksum.clone(),
+                dependencies: pkg.dependencies.iter()
+                    .map(|dep| parse_dependency(dep))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Parses a dependency entry, formatted as `name [version [(source)]]`
+fn parse_dependency(dependency: &str) -> PackageRef {
+    let mut parts = dependency.split(' ');
+    let name = parts.next().unwrap_or_default();
+
+    PackageRef::new(name.to_string(), parts.next().map(str::to_string))
+}
+
+impl Manifest for CargoLockfile {
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        toml::from_str(content)
+            .map_err(|err| toml_error(content, &err).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_locked_packages() {
+        let lockfile = CargoLockfile::from_str(r#"
+            version = 3
+
+            [[package]]
+            name = "ring-utils"
+            version = "0.0.0"
+            dependencies = [
+             "anyhow",
+             "owo-colors 4.0.0",
+             "syn 2.0.66 (registry+https://github.com/rust-lang/crates.io-index)",
+            ]
+
+            [[package]]
+            name = "anyhow"
+            version = "1.0.86"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "b3d1d046238990b9cf5bcde22a3fb3584ee5cf65fb2765f454ed428c7a0063da"
+        "#).unwrap();
+
+        assert_eq!(lockfile.version, Some(3));
+        assert_eq!(lockfile.resolved_packages(), vec![
+            ResolvedPackage {
+                name: "ring-utils".to_string(),
+                version: "0.0.0".to_string(),
+                source: None,
+                checksum: None,
+                dependencies: vec![
+                    PackageRef::new("anyhow".to_string(), None),
+                    PackageRef::new("owo-colors".to_string(), Some("4.0.0".to_string())),
+                    PackageRef::new("syn".to_string(), Some("2.0.66".to_string())),
+                ],
+            },
+            ResolvedPackage {
+                name: "anyhow".to_string(),
+                version: "1.0.86".to_string(),
+                source: Some("registry+https://github.com/rust-lang/crates.io-index".to_string()),
+                checksum: Some("b3d1d046238990b9cf5bcde22a3fb3584ee5cf65fb2765f454ed428c7a0063da".to_string()),
+                dependencies: vec![],
+            },
+        ]);
+    }
+}
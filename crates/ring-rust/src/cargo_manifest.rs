@@ -1,30 +1,160 @@
-use anyhow::Context;
-use semver::Version;
+use std::collections::BTreeMap;
+use semver::{Version, VersionReq};
 use serde::Deserialize;
 use ring_traits::Manifest;
+use crate::parse_errors::toml_error;
 
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
 pub struct CargoPackage {
     pub name: String,
     #[serde(default)]
     pub version: Option<Version>,
+    #[serde(default)]
+    pub build: Option<CargoBuild>,
+    #[serde(default)]
+    pub autobins: Option<bool>,
+    #[serde(default)]
+    pub autoexamples: Option<bool>,
+    #[serde(default)]
+    pub autobenches: Option<bool>,
+}
+
+/// Value of `package.build`, either a path to the build script or `false` to disable it
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum CargoBuild {
+    Enabled(bool),
+    Path(String),
+}
+
+/// Target section, like `[lib]` or `[[bin]]`
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct CargoTarget {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub proc_macro: bool,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
 pub struct CargoWorkspace {
     pub members: Vec<String>,
+    /// Dependencies inherited by members declaring them with `workspace = true`
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, CargoDependency>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct CargoDependencyDetail {
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub package: Option<String>,
+    #[serde(default)]
+    pub workspace: bool,
+    #[serde(default)]
+    pub optional: bool,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default, alias = "default_features")]
+    pub default_features: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum CargoDependency {
+    Simple(String),
+    Detailed(CargoDependencyDetail),
+}
+
+impl CargoDependency {
+    /// Returns the name of the required package, which may differ from the dependency key
+    pub fn package_name<'a>(&'a self, key: &'a str) -> &'a str {
+        match self {
+            CargoDependency::Detailed(CargoDependencyDetail { package: Some(package), .. }) => package,
+            _ => key,
+        }
+    }
+
+    /// Returns the version requirement, falling back to `workspace` or the dependency path
+    pub fn requirement(&self) -> &str {
+        match self {
+            CargoDependency::Simple(version) => version,
+            CargoDependency::Detailed(CargoDependencyDetail { version: Some(version), .. }) => version,
+            CargoDependency::Detailed(CargoDependencyDetail { workspace: true, .. }) => "workspace",
+            CargoDependency::Detailed(CargoDependencyDetail { path: Some(path), .. }) => path,
+            CargoDependency::Detailed(_) => "*",
+        }
+    }
+
+    /// Returns `true` if the dependency is inherited from `[workspace.dependencies]`
+    pub fn is_workspace(&self) -> bool {
+        matches!(self, CargoDependency::Detailed(CargoDependencyDetail { workspace: true, .. }))
+    }
+
+    /// Returns `true` if the dependency is only enabled by a feature
+    pub fn is_optional(&self) -> bool {
+        matches!(self, CargoDependency::Detailed(CargoDependencyDetail { optional: true, .. }))
+    }
+
+    /// Returns features of the required package enabled by this dependency
+    pub fn features(&self) -> &[String] {
+        match self {
+            CargoDependency::Simple(_) => &[],
+            CargoDependency::Detailed(detail) => &detail.features,
+        }
+    }
+
+    /// Returns `true` if the default features of the required package are enabled
+    pub fn default_features(&self) -> bool {
+        match self {
+            CargoDependency::Simple(_) => true,
+            CargoDependency::Detailed(detail) => detail.default_features.unwrap_or(true),
+        }
+    }
+
+    /// Returns the parsed version requirement, if the dependency has one
+    pub fn version_req(&self) -> Option<VersionReq> {
+        match self {
+            CargoDependency::Simple(version) |
+            CargoDependency::Detailed(CargoDependencyDetail { version: Some(version), .. }) => VersionReq::parse(version).ok(),
+            CargoDependency::Detailed(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
 pub struct CargoManifest {
     pub package: Option<CargoPackage>,
     pub workspace: Option<CargoWorkspace>,
+    pub lib: Option<CargoTarget>,
+    #[serde(default)]
+    pub bin: Vec<CargoTarget>,
+    #[serde(default)]
+    pub example: Vec<CargoTarget>,
+    #[serde(default)]
+    pub bench: Vec<CargoTarget>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, CargoDependency>,
+    #[serde(default)]
+    pub dev_dependencies: BTreeMap<String, CargoDependency>,
+    #[serde(default)]
+    pub build_dependencies: BTreeMap<String, CargoDependency>,
+    #[serde(default)]
+    pub features: BTreeMap<String, Vec<String>>,
 }
 
 impl Manifest for CargoManifest {
-    fn from_str(content: &str) -> anyhow::Result<Self> {
-        toml::from_str(content)
-            .context("Error while parsing cargo manifest")
+    fn from_str(content: &str) -> ring_utils::Result<Self> {
+        serde_path_to_error::deserialize(toml::Deserializer::new(content))
+            .map_err(|err| toml_error(content, err.inner()).with_key(err.path().to_string()).into())
     }
 }
 
@@ -32,6 +162,15 @@ impl Manifest for CargoManifest {
 mod tests {
     use super::*;
 
+    #[test]
+    fn it_should_locate_invalid_fields() {
+        let error = CargoManifest::from_str("[package]\nname = \"test\"\nversion = 1\n").unwrap_err();
+        let error = error.manifest_error().unwrap();
+
+        assert_eq!(error.key(), Some("package.version"));
+        assert_eq!((error.line(), error.column()), (Some(3), Some(11)));
+    }
+
     #[test]
     fn it_should_parse_crate_manifest() {
         let manifest = CargoManifest::from_str(r#"
@@ -42,9 +181,11 @@ mod tests {
         assert_eq!(manifest.unwrap(), CargoManifest {
             package: Some(CargoPackage { 
                 name: "test".to_string(),
-                version: None
+                version: None,
+                ..Default::default()
             }),
-            workspace: None
+            workspace: None,
+            ..Default::default()
         });
     }
 
@@ -59,9 +200,11 @@ mod tests {
         assert_eq!(manifest.unwrap(), CargoManifest {
             package: Some(CargoPackage { 
                 name: "test".to_string(),
-                version: Some(Version::new(1, 0, 0))
+                version: Some(Version::new(1, 0, 0)),
+                ..Default::default()
             }),
-            workspace: None
+            workspace: None,
+            ..Default::default()
         });
     }
 
@@ -76,7 +219,87 @@ mod tests {
             package: None,
             workspace: Some(CargoWorkspace {
                 members: vec!["crates/test-a".to_string(), "crates/test-b".to_string()],
-            })
+                dependencies: BTreeMap::new(),
+            }),
+            ..Default::default()
         });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn it_should_parse_crate_manifest_with_dependencies() {
+        let manifest = CargoManifest::from_str(r#"
+            [package]
+            name = "test"
+
+            [dependencies]
+            anyhow = "1.0.86"
+            ring-utils = { path = "../ring-utils" }
+            toml_edit = { package = "toml-edit", version = "0.22" }
+
+            [dev-dependencies]
+            mockall = { workspace = true }
+
+            [build-dependencies]
+            cc = "1.0"
+        "#).unwrap();
+
+        assert_eq!(manifest.dependencies["anyhow"], CargoDependency::Simple("1.0.86".to_string()));
+        assert_eq!(manifest.dependencies["ring-utils"].requirement(), "../ring-utils");
+        assert_eq!(manifest.dependencies["toml_edit"].package_name("toml_edit"), "toml-edit");
+        assert_eq!(manifest.dependencies["toml_edit"].requirement(), "0.22");
+        assert_eq!(manifest.dev_dependencies["mockall"].requirement(), "workspace");
+        assert!(manifest.dev_dependencies["mockall"].is_workspace());
+        assert_eq!(manifest.build_dependencies["cc"].requirement(), "1.0");
+
+        assert_eq!(manifest.dependencies["anyhow"].version_req(), VersionReq::parse("1.0.86").ok());
+        assert_eq!(manifest.dependencies["ring-utils"].version_req(), None);
+        assert_eq!(manifest.dev_dependencies["mockall"].version_req(), None);
+    }
+
+    #[test]
+    fn it_should_parse_crate_manifest_with_targets() {
+        let manifest = CargoManifest::from_str(r#"
+            [package]
+            name = "test"
+            build = false
+
+            [lib]
+            proc-macro = true
+
+            [[bin]]
+            name = "test-cli"
+            path = "src/cli.rs"
+        "#).unwrap();
+
+        assert_eq!(manifest.package.unwrap().build, Some(CargoBuild::Enabled(false)));
+        assert_eq!(manifest.lib, Some(CargoTarget { proc_macro: true, ..Default::default() }));
+        assert_eq!(manifest.bin, vec![CargoTarget {
+            name: Some("test-cli".to_string()),
+            path: Some("src/cli.rs".to_string()),
+            proc_macro: false,
+        }]);
+    }
+
+    #[test]
+    fn it_should_parse_crate_manifest_with_features() {
+        let manifest = CargoManifest::from_str(r#"
+            [package]
+            name = "test"
+
+            [features]
+            default = ["js"]
+            js = ["dep:ring-js"]
+
+            [dependencies]
+            ring-js = { path = "../ring-js", optional = true }
+            owo-colors = { version = "4.0.0", default-features = false, features = ["supports-colors"] }
+        "#).unwrap();
+
+        assert_eq!(manifest.features["default"], ["js"]);
+        assert!(manifest.dependencies["ring-js"].is_optional());
+        assert!(manifest.dependencies["ring-js"].default_features());
+        assert!(!manifest.dependencies["owo-colors"].is_optional());
+        assert!(!manifest.dependencies["owo-colors"].default_features());
+        assert_eq!(manifest.dependencies["owo-colors"].features(), ["supports-colors"]);
+    }
+}
@@ -1,6 +1,11 @@
-use owo_colors::DynColors::Rgb;
+use owo_colors::AnsiColors;
+use owo_colors::DynColors::{Ansi, Rgb};
 use ring_utils::Tag;
 
+pub const LOCKFILE: &str = "Cargo.lock";
 pub const MANIFEST: &str = "Cargo.toml";
 
-pub const RUST_TAG: Tag = Tag::with_color("rust", Rgb(227, 59, 38));
\ No newline at end of file
+pub const RUST_TAG: Tag = Tag::with_color("rust", Rgb(227, 59, 38));
+pub const BIN_TAG: Tag = Tag::with_color("bin", Ansi(AnsiColors::Green));
+pub const LIB_TAG: Tag = Tag::with_color("lib", Ansi(AnsiColors::Cyan));
+pub const PROC_MACRO_TAG: Tag = Tag::with_color("proc-macro", Ansi(AnsiColors::Magenta));
\ No newline at end of file
@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use crate::CargoManifest;
+
+/// Item enabled by a feature, as written in `[features]`
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FeatureValue {
+    /// Another feature of the same crate
+    Feature(String),
+    /// Optional dependency, written `dep:name`
+    Dependency(String),
+    /// Feature of a dependency, written `name/feature` or `name?/feature` when it does not
+    /// enable the dependency itself
+    DependencyFeature { dependency: String, feature: String, weak: bool },
+}
+
+impl FeatureValue {
+    pub fn parse(value: &str) -> FeatureValue {
+        if let Some(dependency) = value.strip_prefix("dep:") {
+            return FeatureValue::Dependency(dependency.to_string());
+        }
+
+        match value.split_once('/') {
+            Some((dependency, feature)) => match dependency.strip_suffix('?') {
+                Some(dependency) => FeatureValue::DependencyFeature {
+                    dependency: dependency.to_string(),
+                    feature: feature.to_string(),
+                    weak: true,
+                },
+                None => FeatureValue::DependencyFeature {
+                    dependency: dependency.to_string(),
+                    feature: feature.to_string(),
+                    weak: false,
+                },
+            },
+            None => FeatureValue::Feature(value.to_string()),
+        }
+    }
+}
+
+impl Display for FeatureValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeatureValue::Feature(name) => write!(f, "{name}"),
+            FeatureValue::Dependency(name) => write!(f, "dep:{name}"),
+            FeatureValue::DependencyFeature { dependency, feature, weak: false } => write!(f, "{dependency}/{feature}"),
+            FeatureValue::DependencyFeature { dependency, feature, weak: true } => write!(f, "{dependency}?/{feature}"),
+        }
+    }
+}
+
+/// Feature graph of a crate, including implicit features of optional dependencies
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CrateFeatures {
+    features: BTreeMap<String, Vec<FeatureValue>>,
+    optionals: BTreeSet<String>,
+}
+
+impl CrateFeatures {
+    pub fn new(manifest: &CargoManifest) -> CrateFeatures {
+        let mut features: BTreeMap<String, Vec<FeatureValue>> = manifest.features.iter()
+            .map(|(name, values)| (name.clone(), values.iter().map(|value| FeatureValue::parse(value)).collect()))
+            .collect();
+
+        // Optional dependencies get an implicit feature, unless a feature uses `dep:` syntax for them
+        let optionals = manifest.dependencies.iter()
+            .chain(&manifest.build_dependencies)
+            .filter(|(_, dependency)| dependency.is_optional())
+            .map(|(key, _)| key.clone())
+            .collect::<BTreeSet<_>>();
+
+        for key in &optionals {
+            let explicit = features.values().flatten()
+                .any(|value| matches!(value, FeatureValue::Dependency(name) if name == key));
+
+            if !explicit {
+                features.entry(key.clone())
+                    .or_insert_with(|| vec![FeatureValue::Dependency(key.clone())]);
+            }
+        }
+
+        CrateFeatures { features, optionals }
+    }
+
+    /// Returns every feature, with the values it enables
+    pub fn features(&self) -> &BTreeMap<String, Vec<FeatureValue>> {
+        &self.features
+    }
+
+    /// Returns names of optional dependencies
+    pub fn optional_dependencies(&self) -> &BTreeSet<String> {
+        &self.optionals
+    }
+
+    /// Returns values enabled by the `default` feature
+    pub fn defaults(&self) -> &[FeatureValue] {
+        self.features.get("default").map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns every value enabled by given features, following features enabling other ones
+    pub fn resolve<'a>(&self, enabled: impl IntoIterator<Item = &'a str>) -> BTreeSet<FeatureValue> {
+        let mut resolved = BTreeSet::new();
+        let mut queue = enabled.into_iter()
+            .map(|name| FeatureValue::Feature(name.to_string()))
+            .collect::<Vec<_>>();
+
+        while let Some(value) = queue.pop() {
+            if !resolved.insert(value.clone()) {
+                continue;
+            }
+
+            match &value {
+                FeatureValue::Feature(name) => {
+                    queue.extend(self.features.get(name).into_iter().flatten().cloned());
+                }
+                // Strong dependency features also enable optional dependencies
+                FeatureValue::DependencyFeature { dependency, weak: false, .. } if self.optionals.contains(dependency) => {
+                    match self.features.contains_key(dependency) {
+                        true => queue.push(FeatureValue::Feature(dependency.clone())),
+                        false => queue.push(FeatureValue::Dependency(dependency.clone())),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ring_traits::Manifest;
+    use super::*;
+
+    fn manifest() -> CargoManifest {
+        CargoManifest::from_str(r#"
+            [package]
+            name = "ring-cli"
+
+            [features]
+            default = ["js", "rust"]
+            js = ["ring-core/js"]
+            rust = ["ring-core/rust", "dep:ring-rust"]
+            colors = ["owo-colors?/supports-colors"]
+
+            [dependencies]
+            ring-core = { path = "../ring-core" }
+            ring-rust = { path = "../ring-rust", optional = true }
+            owo-colors = { version = "4.0.0", optional = true }
+        "#).unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_feature_values() {
+        assert_eq!(FeatureValue::parse("js"), FeatureValue::Feature("js".to_string()));
+        assert_eq!(FeatureValue::parse("dep:ring-js"), FeatureValue::Dependency("ring-js".to_string()));
+        assert_eq!(FeatureValue::parse("serde?/derive"), FeatureValue::DependencyFeature {
+            dependency: "serde".to_string(),
+            feature: "derive".to_string(),
+            weak: true,
+        });
+        assert_eq!(format!("{}", FeatureValue::parse("serde?/derive")), "serde?/derive");
+    }
+
+    #[test]
+    fn it_should_add_implicit_features_for_optional_dependencies() {
+        let features = CrateFeatures::new(&manifest());
+
+        assert_eq!(features.features().keys().collect::<Vec<_>>(), ["colors", "default", "js", "owo-colors", "rust"]);
+        assert_eq!(features.features()["owo-colors"], [FeatureValue::Dependency("owo-colors".to_string())]);
+        assert_eq!(features.defaults(), [FeatureValue::parse("js"), FeatureValue::parse("rust")]);
+    }
+
+    #[test]
+    fn it_should_resolve_enabled_values() {
+        let features = CrateFeatures::new(&manifest());
+
+        assert_eq!(features.resolve(["default"]), BTreeSet::from([
+            FeatureValue::parse("default"),
+            FeatureValue::parse("js"),
+            FeatureValue::parse("rust"),
+            FeatureValue::parse("ring-core/js"),
+            FeatureValue::parse("ring-core/rust"),
+            FeatureValue::parse("dep:ring-rust"),
+        ]));
+        assert!(!features.resolve(["colors"]).contains(&FeatureValue::parse("dep:owo-colors")));
+    }
+}
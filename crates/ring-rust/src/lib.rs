@@ -1,18 +1,26 @@
+mod cargo_lockfile;
 mod cargo_manifest;
 mod constants;
+mod features;
+mod parse_errors;
 mod project;
 mod project_detector;
 mod scope;
 mod scope_detector;
+mod target;
 
 use std::rc::Rc;
 use tracing::debug;
-pub use cargo_manifest::{CargoManifest, CargoPackage, CargoWorkspace};
+pub use cargo_lockfile::{CargoLockfile, CargoLockfilePackage};
+pub use cargo_manifest::{CargoBuild, CargoDependency, CargoDependencyDetail, CargoManifest, CargoPackage, CargoTarget, CargoWorkspace};
+pub use features::{CrateFeatures, FeatureValue};
 pub use project::RustProject;
 pub use project_detector::RustProjectDetector;
+use ring_files::{FileSystem, RealFileSystem};
 use ring_traits::{Module, ProjectDetector, ScopeDetector, TaggedDetector};
 pub use scope::RustScope;
 pub use scope_detector::RustScopeDetector;
+pub use target::{RustTarget, RustTargetKind};
 
 // Module
 #[derive(Debug)]
@@ -23,8 +31,13 @@ pub struct RustModule {
 
 impl RustModule {
     pub fn new() -> RustModule {
+        RustModule::with_fs(Rc::new(RealFileSystem))
+    }
+
+    /// Builds a module detecting projects in given file system
+    pub fn with_fs(fs: Rc<dyn FileSystem>) -> RustModule {
         debug!("Initializing rust module");
-        let project_detector = Rc::new(RustProjectDetector::new());
+        let project_detector = Rc::new(RustProjectDetector::new(fs));
 
         RustModule {
             project_detector: project_detector.clone(),
@@ -40,7 +53,7 @@ impl Default for RustModule {
 }
 
 impl Module for RustModule {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "rust"
     }
     
@@ -0,0 +1,11 @@
+use ring_utils::ManifestError;
+
+/// Converts a toml parse error into a manifest error, located at the start of its span
+pub(crate) fn toml_error(content: &str, err: &toml::de::Error) -> ManifestError {
+    let error = ManifestError::new(err.message());
+
+    match err.span() {
+        Some(span) => error.with_offset(content, span.start),
+        None => error,
+    }
+}
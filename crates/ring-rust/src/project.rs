@@ -1,24 +1,67 @@
+use std::cell::OnceCell;
 use std::rc::Rc;
 use semver::Version;
-use ring_traits::{Project, Tagged};
+use ring_files::FileSystem;
+use ring_traits::{Dependency, DependencyKind, Project, Tagged};
 use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
-use crate::{CargoManifest, CargoPackage};
-use crate::constants::RUST_TAG;
+use crate::{CargoDependency, CargoManifest, CargoPackage, CrateFeatures, RustTarget, RustTargetKind};
+use crate::constants::{BIN_TAG, LIB_TAG, MANIFEST, PROC_MACRO_TAG, RUST_TAG};
+use crate::target::discover_targets;
 
 #[derive(Debug)]
 pub struct RustProject {
     root: NormalizedPathBuf,
     manifest: Rc<CargoManifest>,
+    workspace_manifest: Option<Rc<CargoManifest>>,
+    fs: Rc<dyn FileSystem>,
+    targets: OnceCell<Vec<RustTarget>>,
 }
 
 impl RustProject {
-    pub fn new(root: NormalizedPathBuf, manifest: Rc<CargoManifest>) -> RustProject {
-        RustProject { root, manifest }
+    pub fn new(root: NormalizedPathBuf, manifest: Rc<CargoManifest>, fs: Rc<dyn FileSystem>) -> RustProject {
+        RustProject { root, manifest, workspace_manifest: None, fs, targets: OnceCell::new() }
+    }
+
+    /// Sets manifest of the workspace containing the crate, used to resolve inherited dependencies
+    pub fn with_workspace_manifest(mut self, manifest: Rc<CargoManifest>) -> RustProject {
+        self.workspace_manifest = Some(manifest);
+        self
     }
 
     pub fn package(&self) -> &CargoPackage {
         self.manifest.package.as_ref().unwrap()
     }
+
+    /// Returns crate targets, discovered on first call
+    pub fn targets(&self) -> &[RustTarget] {
+        self.targets.get_or_init(|| discover_targets(self.fs.as_ref(), &self.root, &self.manifest))
+    }
+
+    pub fn manifest(&self) -> &CargoManifest {
+        &self.manifest
+    }
+
+    /// Returns crate features
+    pub fn features(&self) -> CrateFeatures {
+        CrateFeatures::new(&self.manifest)
+    }
+
+    /// Returns dependency declared in `[workspace.dependencies]` for inherited dependencies
+    fn inherited_dependency<'a>(&'a self, key: &str, dependency: &'a CargoDependency) -> &'a CargoDependency {
+        if !dependency.is_workspace() {
+            return dependency;
+        }
+
+        self.workspace_manifest.as_ref()
+            .and_then(|manifest| manifest.workspace.as_ref())
+            .and_then(|workspace| workspace.dependencies.get(key))
+            .unwrap_or(dependency)
+    }
+
+    /// Returns `true` if crate has a target of given kind
+    pub fn has_target(&self, kind: RustTargetKind) -> bool {
+        self.targets().iter().any(|target| target.kind() == kind)
+    }
 }
 
 impl Project for RustProject {
@@ -33,10 +76,60 @@ impl Project for RustProject {
     fn version(&self) -> Option<&Version> {
         self.package().version.as_ref()
     }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        let groups = [
+            (&self.manifest.dependencies, DependencyKind::Runtime),
+            (&self.manifest.dev_dependencies, DependencyKind::Dev),
+            (&self.manifest.build_dependencies, DependencyKind::Build),
+        ];
+
+        groups.into_iter()
+            .flat_map(|(deps, kind)| deps.iter()
+                .map(move |(key, dep)| {
+                    let dep = self.inherited_dependency(key, dep);
+
+                    Dependency::new(dep.package_name(key).to_string(), dep.requirement().to_string(), kind)
+                        .with_version_reqs(dep.version_req().into_iter().collect())
+                })
+            )
+            .collect()
+    }
+
+    fn manifest_path(&self) -> Option<NormalizedPathBuf> {
+        Some(self.root.join(MANIFEST))
+    }
+
+    fn details(&self) -> Vec<(String, String)> {
+        let mut details = self.targets().iter()
+            .map(|target| ("targets".to_string(), format!("{} {}", target.kind(), target.name())))
+            .collect::<Vec<_>>();
+
+        for (feature, values) in self.features().features() {
+            let values = values.iter().map(ToString::to_string).collect::<Vec<_>>();
+            details.push(("features".to_string(), format!("{feature}: {}", values.join(", "))));
+        }
+
+        details
+    }
 }
 
 impl Tagged for RustProject {
-    fn tags(&self) ->  &[&'static Tag] {
-        &[&RUST_TAG]
+    fn tags(&self) -> Vec<Tag> {
+        let mut tags = vec![RUST_TAG];
+
+        if self.has_target(RustTargetKind::Lib) {
+            tags.push(LIB_TAG);
+        }
+
+        if self.has_target(RustTargetKind::ProcMacro) {
+            tags.push(PROC_MACRO_TAG);
+        }
+
+        if self.has_target(RustTargetKind::Bin) {
+            tags.push(BIN_TAG);
+        }
+
+        tags
     }
 }
\ No newline at end of file
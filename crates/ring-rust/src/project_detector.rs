@@ -1,6 +1,6 @@
 use crate::constants::MANIFEST;
-use crate::{CargoManifest, RustProject};
-use ring_files::ManifestLoader;
+use crate::{CargoDependency, CargoManifest, RustProject};
+use ring_files::{FileSystem, ManifestLoader, RealFileSystem};
 use ring_traits::{Detect, DetectAs, Project, Tagged, detect_as, detect_from};
 use ring_utils::OptionalResult::{self, Found};
 use ring_utils::{NormalizedPath, PathTree};
@@ -12,24 +12,38 @@ use tracing::{debug, info};
 pub struct RustProjectDetector {
     cache: RefCell<PathTree<Rc<RustProject>>>,
     cargo_loader: ManifestLoader<CargoManifest>,
+    fs: Rc<dyn FileSystem>,
 }
 
 impl RustProjectDetector {
-    pub fn new() -> RustProjectDetector {
+    pub fn new(fs: Rc<dyn FileSystem>) -> RustProjectDetector {
         RustProjectDetector {
             cache: RefCell::new(PathTree::new()),
-            cargo_loader: ManifestLoader::new(MANIFEST),
+            cargo_loader: ManifestLoader::new(MANIFEST, fs.clone()),
+            fs,
         }
     }
 
+    pub fn fs(&self) -> &Rc<dyn FileSystem> {
+        &self.fs
+    }
+
     pub(crate) fn cargo_loader(&self) -> &ManifestLoader<CargoManifest> {
         &self.cargo_loader
     }
+
+    /// Searches manifest of the nearest workspace containing given crate
+    fn find_workspace_manifest(&self, path: &NormalizedPath) -> Option<Rc<CargoManifest>> {
+        path.ancestors()
+            .filter_map(|ancestor| Option::from(self.cargo_loader.load(ancestor))
+                .and_then(Result::ok))
+            .find(|mnf: &Rc<CargoManifest>| mnf.workspace.is_some())
+    }
 }
 
 impl Default for RustProjectDetector {
     fn default() -> Self {
-        RustProjectDetector::new()
+        RustProjectDetector::new(Rc::new(RealFileSystem))
     }
 }
 
@@ -37,7 +51,7 @@ impl Detect for RustProjectDetector {
     type Item = Rc<RustProject>;
 
     fn detect_at(&self, path: &NormalizedPath) -> OptionalResult<Self::Item> {
-        let path = if path.is_file() { path.parent().unwrap() } else { path };
+        let path = if self.fs.is_file(path) { path.parent().unwrap() } else { path };
 
         if let Some(project) = self.cache.borrow().get(path) {
             debug!("Found rust project {} at {} (cached)", project.name(), path.display());
@@ -46,7 +60,19 @@ impl Detect for RustProjectDetector {
 
         self.cargo_loader.load(path)
             .filter(|mnf| mnf.package.is_some())
-            .map(|mnf| Rc::new(RustProject::new(path.to_path_buf(), mnf)))
+            .map(|mnf| {
+                let inherits = mnf.dependencies.values()
+                    .chain(mnf.dev_dependencies.values())
+                    .chain(mnf.build_dependencies.values())
+                    .any(CargoDependency::is_workspace);
+                let project = RustProject::new(path.to_path_buf(), mnf, self.fs.clone());
+
+                // Inherited dependencies are declared in the workspace manifest
+                match inherits.then(|| self.find_workspace_manifest(path)).flatten() {
+                    Some(workspace) => Rc::new(project.with_workspace_manifest(workspace)),
+                    None => Rc::new(project),
+                }
+            })
             .inspect(|prj| {
                 debug!("Found rust project {} at {}", prj.name(), path.display());
                 self.cache.borrow_mut().set(path, prj.clone());
@@ -60,4 +86,28 @@ impl Detect for RustProjectDetector {
 }
 
 detect_as!(RustProjectDetector, Rc<dyn Project>);
-detect_as!(RustProjectDetector, Rc<dyn Tagged>);
\ No newline at end of file
+detect_as!(RustProjectDetector, Rc<dyn Tagged>);
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use ring_files::MemoryFileSystem;
+    use ring_utils::Normalize;
+    use super::*;
+
+    #[test]
+    fn it_should_resolve_inherited_dependencies() {
+        let root = Path::new("/repo").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\nserde = \"1.0.203\"\n");
+        fs.add_file(&root.join("crates/core/Cargo.toml"), "[package]\nname = \"core\"\n\n[dependencies]\nserde = { workspace = true }\n");
+
+        let detector = RustProjectDetector::new(Rc::new(fs));
+        let Found(project) = detector.detect_at(&root.join("crates/core")) else { panic!("crate should be found") };
+        let dependencies = project.dependencies();
+
+        assert_eq!(dependencies[0].requirement(), "1.0.203");
+        assert!(dependencies[0].accepts("1.0.210"));
+        assert!(!dependencies[0].accepts("1.0.190"));
+    }
+}
@@ -1,10 +1,10 @@
 use std::rc::Rc;
-use tracing::{debug, warn};
-use ring_files::PatternIterator;
-use ring_traits::{ProjectIterator, Scope, Tagged};
-use ring_utils::{NormalizedPath, NormalizedPathBuf, Tag};
-use crate::{CargoManifest, CargoWorkspace, RustProjectDetector};
-use crate::constants::RUST_TAG;
+use tracing::debug;
+use ring_files::{ManifestLoader, PatternIterator};
+use ring_traits::{Detect, DetectAs, Project, ProjectIterator, ResolvedPackage, Scope, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf, OptionalResult, Tag};
+use crate::{CargoLockfile, CargoManifest, CargoWorkspace, RustProject, RustProjectDetector};
+use crate::constants::{LOCKFILE, RUST_TAG};
 
 #[derive(Debug)]
 pub struct RustScope {
@@ -21,6 +21,17 @@ impl RustScope {
     pub fn workspace(&self) -> &CargoWorkspace {
         self.manifest.workspace.as_ref().unwrap()
     }
+
+    /// Returns an iterator over workspace members
+    pub fn rust_projects(&self) -> impl Iterator<Item = ring_utils::Result<Rc<RustProject>>> + '_ {
+        let detector: Rc<dyn DetectAs<Rc<RustProject>>> = self.project_detector.clone();
+
+        self.workspace().members.iter()
+            .resolve(self.root())
+            .inspect(|pattern| debug!("Search rust project matching {}", pattern.display()))
+            .glob_search(self.project_detector.fs().clone())
+            .try_detect_at(detector)
+    }
 }
 
 impl Scope for RustScope {
@@ -28,23 +39,28 @@ impl Scope for RustScope {
         &self.root
     }
 
-    fn projects(&self) -> Box<ProjectIterator> {
-        let projects = self.workspace().members.iter()
-            .resolve(self.root())
-            .inspect(|pattern| debug!("Search rust project matching {}", pattern.display()))
-            .glob_search()
-            .filter_map(|result| result
-                .inspect_err(|err| warn!("Error while loading scope project {:#}", err))
-                .ok()
-            )
-            .detect_at(self.project_detector.clone());
+    fn root_project(&self) -> OptionalResult<Rc<dyn Project>> {
+        self.project_detector.detect_at(&self.root)
+            .map(|prj| prj as Rc<dyn Project>)
+    }
+
+    fn projects(&self) -> Box<ProjectIterator<'_>> {
+        Box::new(self.rust_projects().map(|prj| prj.map(|prj| prj as Rc<dyn Project>)))
+    }
+
+    fn package_manager_name(&self) -> Option<&str> {
+        Some("cargo")
+    }
 
-        Box::new(projects)
+    fn resolved_packages(&self) -> OptionalResult<Vec<ResolvedPackage>> {
+        ManifestLoader::<CargoLockfile>::new(LOCKFILE, self.project_detector.fs().clone())
+            .load(self.root())
+            .map(|lockfile| lockfile.resolved_packages())
     }
 }
 
 impl Tagged for RustScope {
-    fn tags(&self) ->  &[&'static Tag] {
-        &[&RUST_TAG]
+    fn tags(&self) -> Vec<Tag> {
+        vec![RUST_TAG]
     }
 }
\ No newline at end of file
@@ -0,0 +1,263 @@
+use std::fmt::{Display, Formatter};
+use ring_files::FileSystem;
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use crate::{CargoBuild, CargoManifest, CargoTarget};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RustTargetKind {
+    Lib,
+    ProcMacro,
+    Bin,
+    Example,
+    Bench,
+    BuildScript,
+}
+
+impl Display for RustTargetKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RustTargetKind::Lib => write!(f, "lib"),
+            RustTargetKind::ProcMacro => write!(f, "proc-macro"),
+            RustTargetKind::Bin => write!(f, "bin"),
+            RustTargetKind::Example => write!(f, "example"),
+            RustTargetKind::Bench => write!(f, "bench"),
+            RustTargetKind::BuildScript => write!(f, "build-script"),
+        }
+    }
+}
+
+/// Target of a rust crate, declared in its manifest or following cargo conventions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RustTarget {
+    kind: RustTargetKind,
+    name: String,
+    path: NormalizedPathBuf,
+}
+
+impl RustTarget {
+    pub fn new(kind: RustTargetKind, name: String, path: NormalizedPathBuf) -> RustTarget {
+        RustTarget { kind, name, path }
+    }
+
+    pub fn kind(&self) -> RustTargetKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns path of target entry point
+    pub fn path(&self) -> &NormalizedPath {
+        &self.path
+    }
+}
+
+/// Lists targets of crate at given root, like cargo does
+pub(crate) fn discover_targets(fs: &dyn FileSystem, root: &NormalizedPath, manifest: &CargoManifest) -> Vec<RustTarget> {
+    let Some(package) = &manifest.package else { return Vec::new() };
+    let mut targets = Vec::new();
+
+    // Library
+    let lib_path = manifest.lib.as_ref()
+        .and_then(|lib| lib.path.as_ref())
+        .map_or_else(|| root.join("src/lib.rs"), |path| root.join(path));
+
+    if manifest.lib.is_some() || fs.is_file(&lib_path) {
+        let lib = manifest.lib.as_ref();
+        let kind = if lib.is_some_and(|lib| lib.proc_macro) { RustTargetKind::ProcMacro } else { RustTargetKind::Lib };
+        let name = lib.and_then(|lib| lib.name.clone())
+            .unwrap_or_else(|| package.name.replace('-', "_"));
+
+        targets.push(RustTarget::new(kind, name, lib_path));
+    }
+
+    // Binaries, examples and benches
+    let mut main = Vec::new();
+
+    if fs.is_file(&root.join("src/main.rs")) {
+        main.push((package.name.clone(), root.join("src/main.rs")));
+    }
+
+    main.extend(auto_targets(fs, &root.join("src/bin")));
+
+    targets.extend(declared_targets(fs, root, &package.name, RustTargetKind::Bin, &manifest.bin, package.autobins, main));
+    targets.extend(declared_targets(fs, root, &package.name, RustTargetKind::Example, &manifest.example, package.autoexamples, auto_targets(fs, &root.join("examples"))));
+    targets.extend(declared_targets(fs, root, &package.name, RustTargetKind::Bench, &manifest.bench, package.autobenches, auto_targets(fs, &root.join("benches"))));
+
+    // Build script
+    let build_path = match &package.build {
+        Some(CargoBuild::Path(path)) => Some(root.join(path)),
+        Some(CargoBuild::Enabled(false)) => None,
+        Some(CargoBuild::Enabled(true)) | None => Some(root.join("build.rs")).filter(|path| fs.is_file(path)),
+    };
+
+    if let Some(path) = build_path {
+        targets.push(RustTarget::new(RustTargetKind::BuildScript, "build-script-build".to_string(), path));
+    }
+
+    targets
+}
+
+/// Merges targets declared in manifest with discovered ones, unless auto discovery is disabled.
+///
+/// Declared targets without path are searched like cargo does: `src/main.rs` for the binary
+/// named after the package, then `<dir>/<name>.rs` and `<dir>/<name>/main.rs`.
+fn declared_targets(fs: &dyn FileSystem, root: &NormalizedPath, package: &str, kind: RustTargetKind, declared: &[CargoTarget], auto: Option<bool>, discovered: Vec<(String, NormalizedPathBuf)>) -> Vec<RustTarget> {
+    let dir = match kind {
+        RustTargetKind::Example => "examples",
+        RustTargetKind::Bench => "benches",
+        _ => "src/bin",
+    };
+
+    let mut targets = declared.iter()
+        .filter_map(|target| {
+            let name = target.name.clone()?;
+            let path = match &target.path {
+                Some(path) => root.join(path),
+                None => {
+                    let main = Some(root.join("src/main.rs")).filter(|_| kind == RustTargetKind::Bin && name == package);
+                    let file = root.join(dir).join(format!("{name}.rs"));
+
+                    main.into_iter()
+                        .chain([file.clone(), root.join(dir).join(&name).join("main.rs")])
+                        .find(|path| fs.is_file(path))
+                        .unwrap_or(file)
+                }
+            };
+
+            Some(RustTarget::new(kind, name, path))
+        })
+        .collect::<Vec<_>>();
+
+    if auto.unwrap_or(true) {
+        for (name, path) in discovered {
+            if !targets.iter().any(|target| target.name == name || target.path == path) {
+                targets.push(RustTarget::new(kind, name, path));
+            }
+        }
+    }
+
+    targets.sort_by(|a, b| a.name.cmp(&b.name));
+    targets
+}
+
+/// Searches `<name>.rs` files and `<name>/main.rs` files in given directory
+fn auto_targets(fs: &dyn FileSystem, dir: &NormalizedPath) -> Vec<(String, NormalizedPathBuf)> {
+    let Ok(paths) = fs.read_dir(dir) else { return Vec::new() };
+    let mut targets = Vec::new();
+
+    for path in paths {
+        let Some(stem) = path.as_path().file_stem().and_then(|stem| stem.to_str()) else { continue };
+
+        if fs.is_file(&path) && path.extension().is_some_and(|ext| ext == "rs") {
+            targets.push((stem.to_string(), path.clone()));
+        } else if fs.is_file(&path.join("main.rs")) {
+            targets.push((stem.to_string(), path.join("main.rs")));
+        }
+    }
+
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use ring_files::MemoryFileSystem;
+    use ring_traits::Manifest;
+    use ring_utils::Normalize;
+    use super::*;
+
+    fn targets_of(manifest: &str, files: &[&str]) -> Vec<(RustTargetKind, String)> {
+        let root = Path::new("/crate").normalize();
+        let mut fs = MemoryFileSystem::new();
+
+        for file in files {
+            fs.add_file(&root.join(file), "");
+        }
+
+        discover_targets(&fs, &root, &CargoManifest::from_str(manifest).unwrap())
+            .into_iter()
+            .map(|target| (target.kind, target.name))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_discover_conventional_targets() {
+        let targets = targets_of("[package]\nname = \"ring-cli\"", &[
+            "src/lib.rs",
+            "src/main.rs",
+            "src/bin/extra.rs",
+            "src/bin/tool/main.rs",
+            "examples/basic.rs",
+            "benches/speed.rs",
+            "build.rs",
+        ]);
+
+        assert_eq!(targets, [
+            (RustTargetKind::Lib, "ring_cli".to_string()),
+            (RustTargetKind::Bin, "extra".to_string()),
+            (RustTargetKind::Bin, "ring-cli".to_string()),
+            (RustTargetKind::Bin, "tool".to_string()),
+            (RustTargetKind::Example, "basic".to_string()),
+            (RustTargetKind::Bench, "speed".to_string()),
+            (RustTargetKind::BuildScript, "build-script-build".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn it_should_use_targets_declared_in_manifest() {
+        let targets = targets_of(r#"
+[package]
+name = "macros"
+build = false
+autobins = false
+
+[lib]
+proc-macro = true
+
+[[bin]]
+name = "cli"
+path = "src/cli.rs"
+"#, &["src/main.rs", "build.rs"]);
+
+        assert_eq!(targets, [
+            (RustTargetKind::ProcMacro, "macros".to_string()),
+            (RustTargetKind::Bin, "cli".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn it_should_infer_paths_of_declared_targets() {
+        let root = Path::new("/crate").normalize();
+        let mut fs = MemoryFileSystem::new();
+        fs.add_file(&root.join("src/main.rs"), "");
+        fs.add_file(&root.join("src/bin/tool/main.rs"), "");
+
+        let manifest = CargoManifest::from_str(r#"
+[package]
+name = "app"
+autobins = false
+
+[[bin]]
+name = "app"
+
+[[bin]]
+name = "tool"
+
+[[bin]]
+name = "missing"
+"#).unwrap();
+
+        let paths = discover_targets(&fs, &root, &manifest)
+            .into_iter()
+            .map(|target| (target.name, target.path))
+            .collect::<Vec<_>>();
+
+        assert_eq!(paths, [
+            ("app".to_string(), root.join("src/main.rs")),
+            ("missing".to_string(), root.join("src/bin/missing.rs")),
+            ("tool".to_string(), root.join("src/bin/tool/main.rs")),
+        ]);
+    }
+}
@@ -0,0 +1,16 @@
+use std::any::Any;
+
+/// Gives access to the concrete type behind a trait object
+pub trait AsAny {
+    /// Returns self as [`Any`], allowing downcasting to the ecosystem specific type.
+    ///
+    /// Call it on the trait object itself (`project.as_ref().as_any()`), not on its smart pointer.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{Display, Formatter};
+use semver::{Version, VersionReq};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DependencyKind {
+    Runtime,
+    Dev,
+    Build,
+    Peer,
+    Optional,
+}
+
+impl Display for DependencyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyKind::Runtime => write!(f, "runtime"),
+            DependencyKind::Dev => write!(f, "dev"),
+            DependencyKind::Build => write!(f, "build"),
+            DependencyKind::Peer => write!(f, "peer"),
+            DependencyKind::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dependency {
+    name: String,
+    requirement: String,
+    version_reqs: Vec<VersionReq>,
+    kind: DependencyKind,
+}
+
+impl Dependency {
+    pub fn new(name: String, requirement: String, kind: DependencyKind) -> Dependency {
+        Dependency { name, requirement, version_reqs: Vec::new(), kind }
+    }
+
+    /// Sets version ranges accepted by this dependency (any of them may match)
+    pub fn with_version_reqs(mut self, version_reqs: Vec<VersionReq>) -> Dependency {
+        self.version_reqs = version_reqs;
+        self
+    }
+
+    /// Returns name of the required package
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns version requirement, as written in the manifest
+    pub fn requirement(&self) -> &str {
+        &self.requirement
+    }
+
+    /// Returns version ranges parsed from requirement, empty if it is not a version range
+    pub fn version_reqs(&self) -> &[VersionReq] {
+        &self.version_reqs
+    }
+
+    /// Returns kind of dependency
+    pub fn kind(&self) -> DependencyKind {
+        self.kind
+    }
+
+    /// Returns `true` if given version satisfies this dependency.
+    ///
+    /// Dependencies without version ranges (paths, git, workspace, ...) accept any version,
+    /// as well as versions that cannot be parsed.
+    pub fn accepts(&self, version: &str) -> bool {
+        if self.version_reqs.is_empty() {
+            return true;
+        }
+
+        match Version::parse(version) {
+            Ok(version) => self.version_reqs.iter().any(|req| req.matches(&version)),
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_display_dependency_kind() {
+        assert_eq!(format!("{}", DependencyKind::Runtime), "runtime");
+        assert_eq!(format!("{}", DependencyKind::Dev), "dev");
+        assert_eq!(format!("{}", DependencyKind::Build), "build");
+        assert_eq!(format!("{}", DependencyKind::Peer), "peer");
+        assert_eq!(format!("{}", DependencyKind::Optional), "optional");
+    }
+
+    #[test]
+    fn it_should_accept_versions_matching_any_range() {
+        let dependency = Dependency::new("react".to_string(), "^17.0.0 || ^18.0.0".to_string(), DependencyKind::Runtime)
+            .with_version_reqs(vec![
+                VersionReq::parse("^17.0.0").unwrap(),
+                VersionReq::parse("^18.0.0").unwrap(),
+            ]);
+
+        assert!(dependency.accepts("17.0.2"));
+        assert!(dependency.accepts("18.3.1"));
+        assert!(!dependency.accepts("16.14.0"));
+    }
+
+    #[test]
+    fn it_should_accept_any_version_without_ranges() {
+        let dependency = Dependency::new("ring-utils".to_string(), "../ring-utils".to_string(), DependencyKind::Runtime);
+
+        assert!(dependency.accepts("0.0.0"));
+        assert!(dependency.accepts("not-a-version"));
+    }
+}
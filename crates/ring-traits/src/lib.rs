@@ -1,13 +1,19 @@
+mod as_any;
+mod dependency;
 mod detect;
 mod manifest;
 mod module;
 mod project;
+mod resolved_package;
 mod scope;
 mod tagged;
 
+pub use as_any::AsAny;
+pub use dependency::{Dependency, DependencyKind};
 pub use detect::{DetectAs, Detect};
 pub use manifest::Manifest;
 pub use module::Module;
 pub use project::{Project, ProjectDetector, ProjectIterator};
+pub use resolved_package::{PackageRef, ResolvedPackage};
 pub use scope::{Scope, ScopeDetector};
 pub use tagged::{Tagged, TaggedDetector};
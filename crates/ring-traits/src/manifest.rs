@@ -1,12 +1,13 @@
 use std::io::Read;
-use anyhow::Context;
+use ring_utils::Error;
 
 pub trait Manifest : Sized {
-    fn from_str(content: &str) -> anyhow::Result<Self>;
+    fn from_str(content: &str) -> ring_utils::Result<Self>;
 
-    fn from_reader<R: Read>(reader: &mut R) -> anyhow::Result<Self> {
+    fn from_reader<R: Read>(reader: &mut R) -> ring_utils::Result<Self> {
         let mut buffer = String::new();
-        reader.read_to_string(&mut buffer).context("Unable to read")?;
+        reader.read_to_string(&mut buffer)
+            .map_err(|err| Error::io("", err).context("Unable to read manifest"))?;
 
         Self::from_str(&buffer)
     }
@@ -29,7 +30,7 @@ mod tests {
     mock!(
         TestManifest {}
         impl Manifest for TestManifest {
-            fn from_str(content: &str) -> anyhow::Result<Self>;
+            fn from_str(content: &str) -> ring_utils::Result<Self>;
         }
     );
 
@@ -49,4 +50,14 @@ mod tests {
 
         assert!(MockTestManifest::from_reader(&mut reader).is_ok());
     }
+
+    #[test]
+    fn it_should_report_read_failures_as_io_errors() {
+        let mut reader = MockReader::new();
+        reader.expect_read_to_string()
+            .returning(|_| Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied)));
+
+        let error = MockTestManifest::from_reader(&mut reader).err().unwrap();
+        assert_eq!(error.kind(), ring_utils::ErrorKind::PermissionDenied);
+    }
 }
\ No newline at end of file
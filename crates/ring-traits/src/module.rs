@@ -3,7 +3,7 @@ use crate::{ProjectDetector, ScopeDetector};
 use crate::tagged::TaggedDetector;
 
 pub trait Module {
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
     
     #[inline]
     fn project_detectors(&self) -> Vec<Rc<ProjectDetector>> {
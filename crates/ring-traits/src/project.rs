@@ -1,9 +1,9 @@
 use std::rc::Rc;
 use semver::Version;
-use ring_utils::NormalizedPath;
-use crate::{DetectAs, Tagged};
+use ring_utils::{NormalizedPath, NormalizedPathBuf};
+use crate::{AsAny, Dependency, DetectAs, Tagged};
 
-pub trait Project : Tagged {
+pub trait Project : Tagged + AsAny {
     /// Returns project root directory
     fn root(&self) -> &NormalizedPath;
 
@@ -12,7 +12,24 @@ pub trait Project : Tagged {
 
     /// Returns project version (if any)
     fn version(&self) -> Option<&Version>;
+
+    /// Returns dependencies declared by project
+    fn dependencies(&self) -> Vec<Dependency>;
+
+    /// Returns path of project manifest (if any)
+    #[inline]
+    fn manifest_path(&self) -> Option<NormalizedPathBuf> {
+        None
+    }
+
+    /// Returns ecosystem specific information, as label and value pairs.
+    ///
+    /// A label may be repeated to give many values.
+    #[inline]
+    fn details(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
 }
 
 pub type ProjectDetector = dyn DetectAs<Rc<dyn Project>>;
-pub type ProjectIterator<'a> = dyn Iterator<Item = anyhow::Result<Rc<dyn Project>>> + 'a;
\ No newline at end of file
+pub type ProjectIterator<'a> = dyn Iterator<Item = ring_utils::Result<Rc<dyn Project>>> + 'a;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+
+/// Reference to a package, as written in a lockfile dependency list
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PackageRef {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl PackageRef {
+    pub fn new(name: String, version: Option<String>) -> PackageRef {
+        PackageRef { name, version }
+    }
+}
+
+/// Package as resolved by a lockfile
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolvedPackage {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>,
+    pub checksum: Option<String>,
+    pub dependencies: Vec<PackageRef>,
+}
+
+impl ResolvedPackage {
+    /// Returns `true` if given reference points to this package
+    pub fn matches(&self, reference: &PackageRef) -> bool {
+        self.name == reference.name
+            && reference.version.as_ref().is_none_or(|version| &self.version == version)
+    }
+}
+
+impl Display for ResolvedPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_match_reference_with_same_name_and_version() {
+        let package = ResolvedPackage {
+            name: "serde".to_string(),
+            version: "1.0.203".to_string(),
+            ..Default::default()
+        };
+
+        assert!(package.matches(&PackageRef::new("serde".to_string(), None)));
+        assert!(package.matches(&PackageRef::new("serde".to_string(), Some("1.0.203".to_string()))));
+        assert!(!package.matches(&PackageRef::new("serde".to_string(), Some("1.0.190".to_string()))));
+        assert!(!package.matches(&PackageRef::new("toml".to_string(), None)));
+    }
+
+    #[test]
+    fn it_should_display_name_and_version() {
+        let package = ResolvedPackage {
+            name: "serde".to_string(),
+            version: "1.0.203".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(format!("{package}"), "serde@1.0.203");
+    }
+}
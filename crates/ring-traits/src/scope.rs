@@ -1,13 +1,31 @@
 use std::rc::Rc;
-use ring_utils::NormalizedPath;
-use crate::{DetectAs, ProjectIterator, Tagged};
+use ring_utils::{NormalizedPath, OptionalResult};
+use crate::{DetectAs, Project, ProjectIterator, ResolvedPackage, Tagged};
 
 pub trait Scope : Tagged {
     /// Returns scope root directory
     fn root(&self) -> &NormalizedPath;
 
+    /// Returns project located at scope root (if any)
+    #[inline]
+    fn root_project(&self) -> OptionalResult<Rc<dyn Project>> {
+        OptionalResult::Empty
+    }
+
     /// Returns an iterator over scope projects
     fn projects(&self) -> Box<ProjectIterator<'_>>;
+
+    /// Returns name of the tool managing scope packages (if any)
+    #[inline]
+    fn package_manager_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns packages resolved by scope lockfile (if any)
+    #[inline]
+    fn resolved_packages(&self) -> OptionalResult<Vec<ResolvedPackage>> {
+        OptionalResult::Empty
+    }
 }
 
 pub type ScopeDetector = dyn DetectAs<Rc<dyn Scope>>;
\ No newline at end of file
@@ -4,7 +4,7 @@ use crate::DetectAs;
 
 pub trait Tagged {
     /// Return some tags on entity
-    fn tags(&self) -> &[&'static Tag];
+    fn tags(&self) -> Vec<Tag>;
 }
 
 pub type TaggedDetector = dyn DetectAs<Rc<dyn Tagged>>;
@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::ManifestError;
+
+/// Errors raised by ring libraries
+#[derive(Debug)]
+pub enum Error {
+    /// A manifest, lockfile or configuration file could not be parsed
+    Manifest(Box<ManifestError>),
+    /// A file or directory could not be accessed
+    Io { path: PathBuf, source: io::Error },
+    /// A glob pattern is invalid
+    Pattern { pattern: String, message: String },
+    /// Configuration refers to something invalid (unknown module, invalid project type, ...)
+    Config(String),
+    /// An external program (git, plugin, ...) failed
+    Command { program: String, message: String },
+    /// Another error, with a message explaining what was done when it occurred
+    Context { message: String, source: Box<Error> },
+}
+
+/// Kind of error, stable across contexts added to it
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorKind {
+    Manifest,
+    NotFound,
+    PermissionDenied,
+    Io,
+    Pattern,
+    Config,
+    Command,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Manifest => write!(f, "manifest"),
+            ErrorKind::NotFound => write!(f, "not-found"),
+            ErrorKind::PermissionDenied => write!(f, "permission-denied"),
+            ErrorKind::Io => write!(f, "io"),
+            ErrorKind::Pattern => write!(f, "pattern"),
+            ErrorKind::Config => write!(f, "config"),
+            ErrorKind::Command => write!(f, "command"),
+        }
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// Builds an error for a failed access to given path
+    pub fn io(path: impl AsRef<Path>, source: io::Error) -> Error {
+        Error::Io { path: path.as_ref().to_path_buf(), source }
+    }
+
+    pub fn pattern(pattern: impl Display, message: impl Display) -> Error {
+        Error::Pattern { pattern: pattern.to_string(), message: message.to_string() }
+    }
+
+    pub fn config(message: impl Display) -> Error {
+        Error::Config(message.to_string())
+    }
+
+    pub fn command(program: impl Display, message: impl Display) -> Error {
+        Error::Command { program: program.to_string(), message: message.to_string() }
+    }
+
+    /// Wraps error with given context message
+    pub fn context(self, message: impl Display) -> Error {
+        Error::Context { message: message.to_string(), source: Box::new(self) }
+    }
+
+    /// Returns the error under every context
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            err => err,
+        }
+    }
+
+    /// Returns the kind of the root error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use ring_utils::{Error, ErrorKind};
+    ///
+    /// let error = Error::io("/test/package.json", io::Error::from(io::ErrorKind::PermissionDenied))
+    ///     .context("Error while loading project");
+    ///
+    /// assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self.root() {
+            Error::Manifest(_) => ErrorKind::Manifest,
+            Error::Io { source, .. } => match source.kind() {
+                io::ErrorKind::NotFound => ErrorKind::NotFound,
+                io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+                _ => ErrorKind::Io,
+            },
+            Error::Pattern { .. } => ErrorKind::Pattern,
+            Error::Config(_) => ErrorKind::Config,
+            Error::Command { .. } => ErrorKind::Command,
+            Error::Context { .. } => unreachable!(),
+        }
+    }
+
+    /// Returns the manifest error under every context, if any
+    pub fn manifest_error(&self) -> Option<&ManifestError> {
+        match self.root() {
+            Error::Manifest(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Manifest(err) => write!(f, "{err}"),
+            // Readers have no path
+            Error::Io { path, .. } if path.as_os_str().is_empty() => write!(f, "Unable to read"),
+            Error::Io { path, .. } => write!(f, "Unable to access {}", path.display()),
+            Error::Pattern { pattern, message } => write!(f, "Invalid pattern {pattern}: {message}"),
+            Error::Config(message) => write!(f, "{message}"),
+            Error::Command { program, message } => write!(f, "{program} failed: {message}"),
+            Error::Context { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ManifestError> for Error {
+    fn from(err: ManifestError) -> Self {
+        Error::Manifest(Box::new(err))
+    }
+}
+
+/// Adds context to errors, like [`Error::context`]
+pub trait Context<T> {
+    fn context(self, message: impl Display) -> Result<T>;
+
+    fn with_context<M: Display>(self, f: impl FnOnce() -> M) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for Result<T, E> {
+    #[inline]
+    fn context(self, message: impl Display) -> Result<T> {
+        self.map_err(|err| err.into().context(message))
+    }
+
+    #[inline]
+    fn with_context<M: Display>(self, f: impl FnOnce() -> M) -> Result<T> {
+        self.map_err(|err| err.into().context(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use super::*;
+
+    #[test]
+    fn it_should_keep_root_error_under_contexts() {
+        let error: Result<()> = Err(ManifestError::new("missing field `name`"))
+            .context("Error while parsing /test/package.json")
+            .context("Error while loading scope");
+        let error = error.unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Manifest);
+        assert_eq!(error.manifest_error().map(|err| err.message()), Some("missing field `name`"));
+        assert_eq!(error.to_string(), "Error while loading scope");
+        assert_eq!(error.source().unwrap().to_string(), "Error while parsing /test/package.json");
+    }
+}
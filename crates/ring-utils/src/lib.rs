@@ -1,10 +1,16 @@
+mod error;
 mod macros;
+mod manifest_error;
 mod normalized_path;
 mod optional_result;
+mod partial_result;
 mod path_tree;
 mod tag;
 
+pub use error::{Context, Error, ErrorKind, Result};
+pub use manifest_error::ManifestError;
 pub use normalized_path::*;
 pub use optional_result::OptionalResult;
+pub use partial_result::PartialResult;
 pub use path_tree::PathTree;
 pub use tag::Tag;
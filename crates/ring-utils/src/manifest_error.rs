@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use crate::NormalizedPathBuf;
+
+/// Error raised by an invalid manifest, locating the problem in the manifest content
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ManifestError {
+    message: String,
+    path: Option<NormalizedPathBuf>,
+    key: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    content: Option<String>,
+}
+
+impl ManifestError {
+    pub fn new(message: impl Into<String>) -> ManifestError {
+        ManifestError { message: message.into(), ..Default::default() }
+    }
+
+    /// Builds an error from a parser message, located at given line and column. Location
+    /// appended by parsers to their messages (like ` at line 3 column 11`) is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_utils::ManifestError;
+    ///
+    /// let error = ManifestError::at("trailing comma at line 3 column 11", 3, 11);
+    ///
+    /// assert_eq!(error.message(), "trailing comma");
+    /// assert_eq!(error.line(), Some(3));
+    /// ```
+    pub fn at(message: &str, line: usize, column: usize) -> ManifestError {
+        let message = message.strip_suffix(&format!(" at line {line} column {column}")).unwrap_or(message);
+
+        ManifestError::new(message).with_location(line, column)
+    }
+
+    /// Sets path of the invalid manifest file
+    pub fn with_path(mut self, path: NormalizedPathBuf) -> ManifestError {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets dot separated path of the offending key (like `dependencies.semver`).
+    /// Root key (empty or `.`) is ignored.
+    pub fn with_key(mut self, key: impl Into<String>) -> ManifestError {
+        let key = key.into();
+
+        if !key.is_empty() && key != "." {
+            self.key = Some(key);
+        }
+
+        self
+    }
+
+    /// Sets location of the problem, line and column start at 1
+    pub fn with_location(mut self, line: usize, column: usize) -> ManifestError {
+        self.line = Some(line).filter(|&line| line > 0);
+        self.column = Some(column).filter(|&column| column > 0);
+        self
+    }
+
+    /// Sets location of the problem from a byte offset in given content
+    pub fn with_offset(self, content: &str, offset: usize) -> ManifestError {
+        let before = &content[..offset.min(content.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |start| start.chars().count()) + 1;
+
+        self.with_location(line, column)
+    }
+
+    /// Sets content of the invalid manifest, used to render snippets
+    pub fn with_content(mut self, content: impl Into<String>) -> ManifestError {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Raw message of the parser
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn path(&self) -> Option<&NormalizedPathBuf> {
+        self.path.as_ref()
+    }
+
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// Returns the line of content where the problem is located
+    pub fn source_line(&self) -> Option<&str> {
+        self.content.as_ref()?.lines().nth(self.line? - 1)
+    }
+
+    /// Rewords usual serde messages into a human-friendly explanation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_utils::ManifestError;
+    ///
+    /// let error = ManifestError::new("invalid type: integer `1`, expected a string")
+    ///     .with_key("version");
+    ///
+    /// assert_eq!(error.explanation(), "version should be a string, but is an integer `1`");
+    /// ```
+    pub fn explanation(&self) -> String {
+        let subject = self.key.as_deref().unwrap_or("manifest");
+
+        if let Some(rest) = self.message.strip_prefix("invalid type: ") {
+            if let Some((found, expected)) = rest.split_once(", expected ") {
+                return format!("{subject} should be {expected}, but is {}", with_article(found));
+            }
+        }
+
+        if let Some(rest) = self.message.strip_prefix("invalid value: ") {
+            if let Some((found, expected)) = rest.split_once(", expected ") {
+                return format!("{subject} should be {expected}, but is {found}");
+            }
+        }
+
+        if let Some(field) = self.message.strip_prefix("missing field ") {
+            return format!("{subject} is missing required field {field}");
+        }
+
+        if let Some(rest) = self.message.strip_prefix("unknown field ") {
+            let field = rest.split_once(", expected").map_or(rest, |(field, _)| field);
+
+            // Key of unknown fields targets the field itself
+            let parent = self.key.as_deref()
+                .filter(|key| field.trim_matches('`') == key.rsplit('.').next().unwrap_or(key))
+                .map(|key| key.rsplit_once('.').map_or("manifest", |(parent, _)| parent))
+                .unwrap_or(subject);
+
+            return format!("{parent} contains unsupported field {field}");
+        }
+
+        self.message.clone()
+    }
+}
+
+fn with_article(found: &str) -> String {
+    match found.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => format!("an {found}"),
+        _ => format!("a {found}"),
+    }
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}", path.display())?;
+
+            if let (Some(line), Some(column)) = (self.line, self.column) {
+                write!(f, ":{line}:{column}")?;
+            }
+
+            write!(f, ": ")?;
+        }
+
+        write!(f, "{}", self.explanation())
+    }
+}
+
+impl Error for ManifestError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_compute_location_from_offset() {
+        let error = ManifestError::new("test").with_offset("[package]\nname = 1\n", 17);
+
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(8));
+    }
+
+    #[test]
+    fn it_should_explain_serde_messages() {
+        assert_eq!(
+            ManifestError::new("missing field `name`").with_key("package").explanation(),
+            "package is missing required field `name`"
+        );
+        assert_eq!(
+            ManifestError::new("unknown field `nmae`, expected one of `name`, `version`").explanation(),
+            "manifest contains unsupported field `nmae`"
+        );
+        assert_eq!(
+            ManifestError::new("unknown field `colour`, expected `label` or `color`").with_key("output.colour").explanation(),
+            "output contains unsupported field `colour`"
+        );
+        assert_eq!(
+            ManifestError::new("expected `,` or `}`").with_key(".").explanation(),
+            "expected `,` or `}`"
+        );
+    }
+}
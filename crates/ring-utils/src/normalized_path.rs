@@ -337,7 +337,7 @@ impl NormalizedPath {
     /// assert_eq!(components.next(), None);
     /// ```
     #[inline]
-    pub fn components(&self) -> NormalizedComponents {
+    pub fn components(&self) -> NormalizedComponents<'_> {
         NormalizedComponents { inner: self.inner.components() }
     }
 
@@ -487,7 +487,7 @@ impl NormalizedPath {
     /// assert!(Path::new(r"C:\test\toto").normalize().prefix().is_some());
     /// ```
     #[inline]
-    pub fn prefix(&self) -> Option<PrefixComponent> {
+    pub fn prefix(&self) -> Option<PrefixComponent<'_>> {
         self.inner.components().next()
             .and_then(|cmp| if let Component::Prefix(prefix) = cmp { Some(prefix) } else { None })
     }
@@ -838,6 +838,7 @@ impl<P: ?Sized + AsRef<Path>> PartialEq<P> for NormalizedPathBuf {
 
 pub trait Normalize : AsRef<Path> {
     /// Builds a normalized path for current path object
+    #[must_use = "allocated path will be lost if left unused"]
     fn normalize(&self) -> NormalizedPathBuf;
 
     /// Computes a normalized path from self using given base.
@@ -876,7 +877,6 @@ impl Normalize for Path {
     /// let path = Path::new("/foo/baz/../bar").normalize();
     /// assert_eq!(path, Path::new("/foo/bar"));
     /// ```
-    #[must_use = "allocated path will be lost if left unused"]
     fn normalize(&self) -> NormalizedPathBuf {
         let mut components = self.components().peekable();
         let mut inner = match components.peek().cloned() {
@@ -23,7 +23,7 @@ use crate::OptionalResult::{Empty, Fail, Found};
 /// ```
 #[derive(Debug, Eq)]
 #[must_use = "this `OptionalResult` may be an `Fail` variant, which should be handled"]
-pub enum OptionalResult<T, E = anyhow::Error> {
+pub enum OptionalResult<T, E = crate::Error> {
     Found(T),
     Fail(E),
     Empty,
@@ -0,0 +1,103 @@
+/// Values collected despite failures, with the errors met while collecting them
+///
+/// # Examples
+///
+/// ```
+/// use ring_utils::PartialResult;
+///
+/// let result: PartialResult<i32, &str> = vec![Ok(1), Err("failed"), Ok(3)].into_iter().collect();
+///
+/// assert_eq!(result.values(), &[1, 3]);
+/// assert_eq!(result.errors(), &["failed"]);
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct PartialResult<T, E = crate::Error> {
+    values: Vec<T>,
+    errors: Vec<E>,
+}
+
+impl<T, E> PartialResult<T, E> {
+    pub fn new() -> PartialResult<T, E> {
+        PartialResult { values: Vec::new(), errors: Vec::new() }
+    }
+
+    /// Adds value or error to the collection
+    #[inline]
+    pub fn push(&mut self, result: Result<T, E>) {
+        match result {
+            Ok(value) => self.values.push(value),
+            Err(err) => self.errors.push(err),
+        }
+    }
+
+    /// Adds an error to the collection
+    #[inline]
+    pub fn push_err(&mut self, err: E) {
+        self.errors.push(err);
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Returns true if no error were met
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Keeps only values matching given predicate
+    pub fn retain(&mut self, predicate: impl FnMut(&T) -> bool) {
+        self.values.retain(predicate);
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<E>) {
+        (self.values, self.errors)
+    }
+
+    /// Returns values if no error were met, the first error otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ring_utils::PartialResult;
+    ///
+    /// let complete: PartialResult<i32, &str> = vec![Ok(1), Ok(2)].into_iter().collect();
+    /// let failed: PartialResult<i32, &str> = vec![Ok(1), Err("first"), Err("second")].into_iter().collect();
+    ///
+    /// assert_eq!(complete.into_result(), Ok(vec![1, 2]));
+    /// assert_eq!(failed.into_result(), Err("first"));
+    /// ```
+    pub fn into_result(self) -> Result<Vec<T>, E> {
+        match self.errors.into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(self.values),
+        }
+    }
+}
+
+impl<T, E> Default for PartialResult<T, E> {
+    fn default() -> Self {
+        PartialResult::new()
+    }
+}
+
+impl<T, E> Extend<Result<T, E>> for PartialResult<T, E> {
+    fn extend<I: IntoIterator<Item = Result<T, E>>>(&mut self, iter: I) {
+        for result in iter {
+            self.push(result);
+        }
+    }
+}
+
+impl<T, E> FromIterator<Result<T, E>> for PartialResult<T, E> {
+    fn from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Self {
+        let mut result = PartialResult::new();
+        result.extend(iter);
+        result
+    }
+}
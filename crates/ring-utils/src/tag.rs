@@ -1,24 +1,34 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use owo_colors::{DynColors, OwoColorize};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Tag {
-    label: &'static str,
+    label: Cow<'static, str>,
     color: Option<DynColors>,
 }
 
 impl Tag {
     pub const fn new(label: &'static str) -> Tag {
-        Tag { label, color: None }
+        Tag { label: Cow::Borrowed(label), color: None }
     }
 
     pub const fn with_color(label: &'static str, color: DynColors) -> Tag {
-        Tag { label, color: Some(color) }
+        Tag { label: Cow::Borrowed(label), color: Some(color) }
     }
 
-    pub const fn label(&self) -> &'static str {
-        self.label
+    /// Builds a tag with a label computed at runtime
+    pub fn owned(label: String, color: Option<DynColors>) -> Tag {
+        Tag { label: Cow::Owned(label), color }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn color(&self) -> Option<DynColors> {
+        self.color
     }
 }
 
@@ -36,13 +46,13 @@ impl Eq for Tag {}
 
 impl PartialEq for Tag {
     fn eq(&self, other: &Self) -> bool {
-        self.label.eq(other.label)
+        self.label.eq(&other.label)
     }
 }
 
 impl Ord for Tag {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.label.cmp(other.label)
+        self.label.cmp(&other.label)
     }
 }
 
@@ -67,6 +77,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_compare_owned_and_static_labels() {
+        assert_eq!(Tag::owned("testA".to_string(), None), Tag::with_color("testA", Ansi(AnsiColors::Blue)));
+        assert_eq!(
+            format!("{}", Tag::owned("testA".to_string(), Some(Ansi(AnsiColors::Blue)))),
+            format!("{}", "testA".blue())
+        );
+    }
+
     #[test]
     fn it_should_have_same_eq_than_inner_str() {
         assert_eq!(